@@ -12,8 +12,26 @@
 ///
 /// # Arguments
 ///
+/// * `Annotation` - An optional argument to choose whether the type annotation
+///   of a removed declaration is kept as `Satisfies` or `As` at the use site,
+///   or whether annotated declarations are never inlined with `Refuse`.
+///   Default is "Satisfies".
+/// * `Comment` - An optional argument to choose whether comments of removed
+///   declarations `Move` to the use site or `Keep` their place. Default is
+///   "Move".
+/// * `Dead` - An optional argument to `Remove` local declarations that are
+///   never used when their initializer is pure, or with `Expression` to also
+///   remove those with an impure initializer, keeping it as a statement.
+///   Default is "Keep".
+/// * `Dialect` - An optional argument to parse every file as `Typescript`,
+///   `Tsx`, `Javascript` or `Jsx` instead of picking the syntax from its
+///   extension. Default is "Auto".
 /// * `Exclude` - An optional argument to specify patterns to exclude. Default
 ///   is "node_modules".
+/// * `Function` - An optional flag to inline function and class declarations
+///   used once as function and class expressions.
+/// * `Literal` - An optional flag to allow inlining trivially pure literals
+///   into loops, closures and deferred code.
 /// * `Omit` - An optional argument to specify patterns to omit. Default values
 ///   are:
 ///   - "(?i)documentation"
@@ -21,14 +39,21 @@
 ///   - "(?i)changelog\.md$"
 ///   - "(?i)summary\.md$"
 /// * `Parallel` - An optional flag to enable parallel processing.
+/// * `Pass` - An optional argument to cap the inlining passes over a single
+///   file. Default is 16.
 /// * `Pattern` - An optional argument to specify a pattern to match. Default is
 ///   ".git".
+/// * `Reprint` - An optional flag to print whole files again instead of
+///   editing only the changed spans.
 /// * `Root` - An optional argument to specify the root directory. Default is
 ///   ".".
+/// * `Store` - An optional argument to `Report` assignments to local variables
+///   whose value is never read, or to `Remove` them as well, keeping an impure
+///   right-hand side. Default is "Keep".
 ///
 /// # Example
 ///
-/// ```rust
+/// ```ignore
 /// let matches = Fn();
 /// let exclude = matches.value_of("Exclude").unwrap_or("node_modules");
 /// let omit = matches.values_of("Omit").unwrap_or_default().collect::<Vec<_>>();
@@ -46,6 +71,46 @@ pub fn Fn() -> ArgMatches {
 		.version(env!("CARGO_PKG_VERSION"))
 		.author("Source ✍🏻 Open 👐🏻 <Source/Open@PlayForm.LTD>")
 		.about("Eliminate ↘️")
+		.arg(
+			Arg::new("Annotation")
+				.long("Annotation")
+				.display_order(12)
+				.value_name("ANNOTATION")
+				.required(false)
+				.help("Annotation 🏷️")
+				.value_parser(["Satisfies", "As", "Refuse"])
+				.default_value("Satisfies"),
+		)
+		.arg(
+			Arg::new("Comment")
+				.long("Comment")
+				.display_order(9)
+				.value_name("COMMENT")
+				.required(false)
+				.help("Comment 💬")
+				.value_parser(["Move", "Keep"])
+				.default_value("Move"),
+		)
+		.arg(
+			Arg::new("Dead")
+				.long("Dead")
+				.display_order(14)
+				.value_name("DEAD")
+				.required(false)
+				.help("Dead 🪦")
+				.value_parser(["Keep", "Remove", "Expression"])
+				.default_value("Keep"),
+		)
+		.arg(
+			Arg::new("Dialect")
+				.long("Dialect")
+				.display_order(11)
+				.value_name("DIALECT")
+				.required(false)
+				.help("Dialect 🗣️")
+				.value_parser(["Auto", "Typescript", "Tsx", "Javascript", "Jsx"])
+				.default_value("Auto"),
+		)
 		.arg(
 			Arg::new("Exclude")
 				.short('E')
@@ -56,6 +121,24 @@ pub fn Fn() -> ArgMatches {
 				.help("Exclude 🚫")
 				.default_value("node_modules"),
 		)
+		.arg(
+			Arg::new("Function")
+				.long("Function")
+				.action(SetTrue)
+				.display_order(13)
+				.value_name("FUNCTION")
+				.required(false)
+				.help("Function 🧩"),
+		)
+		.arg(
+			Arg::new("Literal")
+				.long("Literal")
+				.action(SetTrue)
+				.display_order(7)
+				.value_name("LITERAL")
+				.required(false)
+				.help("Literal 🔤"),
+		)
 		.arg(
 			Arg::new("Omit")
 				.short('O')
@@ -82,6 +165,16 @@ pub fn Fn() -> ArgMatches {
 				.required(false)
 				.help("Parallel ⏩"),
 		)
+		.arg(
+			Arg::new("Pass")
+				.long("Pass")
+				.display_order(8)
+				.value_name("PASS")
+				.required(false)
+				.help("Pass 🔁")
+				.value_parser(clap::value_parser!(usize))
+				.default_value("16"),
+		)
 		.arg(
 			Arg::new("Pattern")
 				.long("Pattern")
@@ -91,6 +184,15 @@ pub fn Fn() -> ArgMatches {
 				.help("Pattern 🔍")
 				.default_value(".git"),
 		)
+		.arg(
+			Arg::new("Reprint")
+				.long("Reprint")
+				.action(SetTrue)
+				.display_order(10)
+				.value_name("REPRINT")
+				.required(false)
+				.help("Reprint 🖨️"),
+		)
 		.arg(
 			Arg::new("Root")
 				.short('R')
@@ -101,6 +203,16 @@ pub fn Fn() -> ArgMatches {
 				.help("Root 📂")
 				.default_value("."),
 		)
+		.arg(
+			Arg::new("Store")
+				.long("Store")
+				.display_order(15)
+				.value_name("STORE")
+				.required(false)
+				.help("Store 💾")
+				.value_parser(["Keep", "Report", "Remove"])
+				.default_value("Keep"),
+		)
 		.get_matches()
 }
 
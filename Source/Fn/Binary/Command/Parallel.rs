@@ -1,11 +1,11 @@
-/// Asynchronously processes entries to generate summaries and outputs the
-/// results.
+/// Eliminates variables in the provided entries in parallel.
 ///
 /// This function performs the following steps:
-/// 1. Filters and processes the provided entries based on the given pattern and
-///    separator.
-/// 2. Spawns asynchronous tasks to generate summaries for each entry.
-/// 3. Collects the results and outputs them.
+/// 1. Joins the components of each entry with the given separator.
+/// 2. Keeps the entries that are JavaScript or TypeScript sources and match
+///    none of the omit patterns.
+/// 3. Eliminates variables in all of them across the thread pool, writing
+///    each result back.
 ///
 /// # Arguments
 ///
@@ -13,15 +13,14 @@
 ///   - `Entry`: A vector of vectors, where each inner vector contains the
 ///     components of a file path.
 ///   - `Separator`: A character used to join the components of the file path.
-///   - `Pattern`: A string pattern to match against the last element of each
-///     entry.
 ///   - `Omit`: A vector of strings representing patterns to omit.
+///   - `Eliminate`: The options controlling how variables are eliminated.
 ///
 /// # Example
 ///
-/// ```rust
+/// ```ignore
 /// let options = Option {
-/// 	Entry:vec![vec!["path".to_string(), "to".to_string(), "file.git".to_string()]],
+/// 	Entry:vec![vec!["path".to_string(), "to".to_string(), "file.ts".to_string()]],
 /// 	Separator:'/',
 /// 	Pattern:".git".to_string(),
 /// 	Omit:vec!["target".to_string()],
@@ -31,63 +30,26 @@
 ///
 /// # Errors
 ///
-/// This function will log errors if it fails to generate summaries or send
-/// results.
-pub async fn Fn(Option { Entry, Separator, Pattern, Omit, .. }:Option) {
-	// let (Allow, mut Mark) = tokio::sync::mpsc::unbounded_channel();
-
-	// let Queue = futures::stream::FuturesUnordered::new();
-
-	// for Entry in Entry
-	// 	.into_par_iter()
-	// 	.filter_map(|Entry| {
-	// 		Entry
-	// 			.last()
-	// 			.filter(|Last| *Last == &Pattern)
-	// 			.map(|_| Entry[0..Entry.len() - 1].join(&Separator.to_string()))
-	// 	})
-	// 	.collect::<Vec<String>>()
-	// {
-	// 	let Omit = Omit.clone();
-
-	// 	let Allow = Allow.clone();
-
-	// 	Queue.push(tokio::spawn(async move {
-	// 		match crate::Fn::Summary::Fn(
-	// 			&Entry,
-	// 			&crate::Struct::Summary::Difference::Struct { Omit },
-	// 		)
-	// 		.await
-	// 		{
-	// 			Ok(Summary) => {
-	// 				if let Err(_Error) = Allow.send((Entry, Summary)) {
-	// 					eprintln!("Cannot Allow: {}", _Error);
-	// 				}
-	// 			},
-
-	// 			Err(_Error) => {
-	// 				eprintln!("Cannot Summary for {}: {}", Entry, _Error)
-	// 			},
-	// 		}
-	// 	}));
-	// }
-
-	// tokio::spawn(async move {
-	// 	Queue.collect::<Vec<_>>().await;
-
-	// 	drop(Allow);
-	// });
-
-	// let mut Output = Vec::new();
-
-	// while let Some((Entry, Summary)) = Mark.recv().await {
-	// 	Output.push((Entry, Summary));
-	// }
-
-	// crate::Fn::Summary::Group::Fn(Output);
+/// This function will log errors if it fails to process an entry.
+pub async fn Fn(Option { Entry, Separator, Omit, Eliminate, .. }:Option) {
+	let Omit = Omit.iter().map(|Omit| Regex::new(Omit).expect("Cannot Omit.")).collect::<Vec<_>>();
+
+	Entry
+		.into_par_iter()
+		.map(|Entry| Entry.join(&Separator.to_string()))
+		.filter(|Entry| {
+			Path::new(Entry).extension().and_then(|Extension| Extension.to_str()).is_some_and(
+				|Extension| {
+					matches!(Extension, "ts" | "tsx" | "mts" | "cts" | "js" | "jsx" | "mjs" | "cjs")
+				},
+			) && !Omit.iter().any(|Omit| Omit.is_match(Entry))
+		})
+		.for_each(|Entry| crate::Fn::Eliminate::Fn(Path::new(&Entry), &Eliminate));
 }
 
-use futures::stream::StreamExt;
+use std::path::Path;
+
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use regex::Regex;
 
 use crate::Struct::Binary::Command::Entry::Struct as Option;
@@ -1,86 +1,166 @@
-/// main function to process multiple TypeScript files in parallel.
-fn main() -> io::Result<()> {
-	let Paths = vec![
-		Path::new("file1.ts"),
-		Path::new("file2.ts"),
-		// Add more paths here
-	];
-
-	Paths.par_iter().for_each(|Path| {
-		if let Err(E) = ProcessFileRecursive(Path).and_then(|Content| fs::write(Path, Content)) {
-			eprintln!("Error processing {:?}: {}", Path, E);
-		} else {
-			println!("Processed: {:?}", Path);
-		}
-	});
-
-	println!("All files processed.");
-
-	Ok(())
+/// Eliminates variables in the TypeScript file at `Path` with the given
+/// options, writing the result back and reporting how the passes converged.
+pub fn Fn(Path:&Path, Option:&Option) {
+	match ProcessFileRecursive(Path, Option)
+		.and_then(|(Content, Diagnostic)| fs::write(Path, Content).map(|_| Diagnostic))
+	{
+		Err(E) => eprintln!("Error processing {:?}: {}", Path, E),
+		Ok(Diagnostic)
+			if Diagnostic.Convergence != Convergence::Converged
+				|| !Diagnostic.Bailout.is_empty() =>
+		{
+			eprintln!("{:?}: {}", Path, Diagnostic)
+		},
+		Ok(Diagnostic) => println!("Processed: {:?}: {}", Path, Diagnostic),
+	}
 }
 
 /// Recursively processes a TypeScript file, inlining variables until no more
-/// inlining is possible.
-fn ProcessFileRecursive(Path:&Path) -> io::Result<String> {
-	let Cm = SourceMap::default();
-
-	let Code = fs::ReadToString(Path)?;
+/// inlining is possible, and reports how the passes converged.
+///
+/// At most `Option.Pass` passes that change the program run, and only those
+/// are counted. Once the limit is reached, one more pass on a copy tells
+/// whether the file would still change. The passes also stop as soon as one
+/// of them reproduces a program an earlier pass already produced, since the
+/// following passes would only cycle through the same states.
+///
+/// The result is the original text with only the removed declarations and
+/// the replaced uses edited, unless `Option.Reprint` asks for the whole
+/// program to be printed again. Either way the output keeps the line breaks,
+/// byte order mark and indentation of the input.
+fn ProcessFileRecursive(Path:&Path, Option:&Option) -> io::Result<(String, Diagnostic)> {
+	let Cm:Rc<SourceMap> = Default::default();
+
+	let mut Code = fs::read_to_string(Path)?;
+
+	let Style = Style::Detect(&mut Code);
 
 	let Fm = Cm.new_source_file(Rc::new(FileName::Real(Path.to_path_buf())), Code);
 
-	let Lexer = Lexer::new(
-		Syntax::Typescript(Default::default()),
-		Default::default(),
-		StringInput::from(&*Fm),
-		None,
-	);
+	let Comments = SingleThreadedComments::default();
+
+	GLOBALS.set(&Globals::new(), || {
+		let Lexer = Lexer::new(
+			Select(Path, Option.Dialect),
+			Default::default(),
+			StringInput::from(&*Fm),
+			Some(&Comments),
+		);
+
+		let mut Parser = Parser::new_from(Lexer);
+
+		let mut Program = Parse(&mut Parser, Path)?;
+
+		// Give every binding its own `SyntaxContext` so shadowed and same-named
+		// bindings in different scopes resolve to distinct `Id`s.
+		let Unresolved = Mark::new();
+
+		Program.visit_mut_with(&mut resolver(Unresolved, Mark::new(), true));
+
+		let Purity = Purity {
+			Comments:Some(&Comments),
+			Unresolved:SyntaxContext::empty().apply_mark(Unresolved),
+		};
+
+		let mut Inliner = Inliner::New(&Cm, Option, Purity);
+
+		let Bailout = Guard::Scan(&Program)
+			.Bailout
+			.into_iter()
+			.map(|(Reason, Span)| Bailout { Reason, Line:Cm.lookup_char_pos(Span.lo).line })
+			.collect();
+
+		let mut Diagnostic = Diagnostic { Bailout, ..Diagnostic::default() };
+
+		let mut Edits = Vec::new();
+
+		let mut History = vec![Fingerprint(&Program)];
+
+		loop {
+			if Diagnostic.Pass >= Option.Pass {
+				Inliner.Inline(Program.clone());
+
+				if Inliner.Inlined {
+					Diagnostic.Convergence = Convergence::Limit;
+				}
+
+				break;
+			}
 
-	let mut Parser = Parser::new_from(Lexer);
+			Program = Inliner.Inline(Program);
 
-	let mut Module = Parser
-		.parse_module()
-		.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+			Edits.append(&mut Inliner.Edits);
 
-	let mut Inliner = Inliner::New(&Cm);
+			Diagnostic.Removed.append(&mut Inliner.Unused);
+
+			Diagnostic.Store.append(&mut Inliner.Stored);
+
+			if !Inliner.Inlined {
+				break;
+			}
+
+			Diagnostic.Pass += 1;
+
+			let Current = Fingerprint(&Program);
+
+			if let Some(Index) = History.iter().position(|Previous| *Previous == Current) {
+				Diagnostic.Convergence = Convergence::Oscillation(History.len() - Index);
+
+				break;
+			}
 
-	loop {
-		let NewModule = Inliner.Inline(Module);
+			History.push(Current);
 
-		if !Inliner.Inlined {
-			break;
+			Inliner = Inliner::New(&Cm, Option, Purity); // Reset for next iteration
 		}
 
-		Module = NewModule;
+		if Option.Store == Store::Report {
+			Diagnostic.Store = Scanner::Scan(&Program, Purity)
+				.into_iter()
+				.map(|Ident| {
+					Stored { Name:Ident.sym.to_string(), Line:Cm.lookup_char_pos(Ident.span.lo).line }
+				})
+				.collect();
+		}
 
-		Inliner = Inliner::New(&Cm); // Reset for next iteration
-	}
+		if !Option.Reprint {
+			let mut Marker = Marker::default();
 
-	let mut Buf = Vec::new();
+			Program.visit_with(&mut Marker);
 
-	{
-		let mut Printer = JsWriter::new(Rc::new(Cm), "\n", None, None);
+			Edits.append(&mut Marker.Edits);
 
-		swc_ecma_codegen::node::module(&mut Printer, &Module)
-			.map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+			let Code = Editor::Apply(Fm.src.as_str(), Fm.start_pos, Some(&Comments), Edits);
 
-		Buf = Printer.IntoInner();
-	}
+			return Ok((Style.Restore(Code), Diagnostic));
+		}
+
+		let mut Buf = Vec::new();
+
+		{
+			let mut Writer = JsWriter::new(Cm.clone(), Style.Newline, &mut Buf, None);
+
+			Writer.set_indent_str(Style.Indent);
+
+			let mut Emitter =
+				Emitter { cfg:Config::default(), cm:Cm.clone(), comments:Some(&Comments), wr:Writer };
+
+			Emitter.emit_program(&Program)?;
+		}
 
-	Ok(String::from_utf8(Buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?)
+		String::from_utf8(Buf)
+			.map(|Code| (Style.Restore(Code), Diagnostic))
+			.map_err(|E| io::Error::new(io::ErrorKind::InvalidData, E))
+	})
 }
 
-// Helper function to convert to title case
-fn to_title_case(s:&str) -> String {
-	s.chars()
-		.enumerate()
-		.map(|(i, c)| {
-			if i == 0 {
-				c.to_uppercase().next().unwrap()
-			} else {
-				c.to_lowercase().next().unwrap()
-			}
-		})
-		.collect()
+/// Hash of a program, used to notice when a pass reproduces an earlier state.
+fn Fingerprint(Program:&Program) -> u64 {
+	let mut Hasher = DefaultHasher::new();
+
+	Program.hash(&mut Hasher);
+
+	Hasher.finish()
 }
 
 /// `Inliner` struct holds the state needed for inlining variables while
@@ -88,133 +168,572 @@ fn to_title_case(s:&str) -> String {
 struct Inliner<'a> {
 	/// The source map used for tracking source locations.
 	Cm:&'a SourceMap,
-	/// Counts how many times each variable is used.
-	VarUsage:HashMap<String, usize>,
-	/// Stores the initial value expressions for variables.
-	VarDefinitions:HashMap<String, Expr>,
-	/// Tracks which variables are exported and should not be inlined.
-	ExportedVars:HashSet<String>,
+	/// The options controlling how far inlining may go.
+	Option:&'a Option,
+	/// The bindings whose declarators the plan removes.
+	Planned:HashSet<Id>,
+	/// The bindings the plan removes because nothing uses them.
+	Dead:HashSet<Id>,
+	/// Start of the first entry of the program, whose leading comments head
+	/// the file.
+	Header:BytePos,
+	/// The unused declarations removed during the last pass.
+	Unused:Vec<Unused>,
+	/// The dead stores removed during the last pass.
+	Stored:Vec<Stored>,
+	/// Initializers taken from removed declarations, waiting for their single
+	/// use.
+	VarDefinitions:HashMap<Id, Definition>,
+	/// Decides which initializers can be moved without reordering side
+	/// effects.
+	Purity:Purity<'a>,
 	/// Flag to indicate if any inlining occurred during the last pass.
 	Inlined:bool,
+	/// Text edits reproducing the rewrite on the original source.
+	Edits:Vec<Edit>,
 }
 
 impl<'a> Inliner<'a> {
-	/// Creates a new `Inliner` instance with the given `SourceMap`.
-	fn New(Cm:&'a SourceMap) -> Self {
+	/// Creates a new `Inliner` instance with the given `SourceMap`, options
+	/// and purity rules.
+	fn New(Cm:&'a SourceMap, Option:&'a Option, Purity:Purity<'a>) -> Self {
 		Inliner {
 			Cm,
-			VarUsage:HashMap::new(),
+			Option,
+			Planned:HashSet::new(),
+			Dead:HashSet::new(),
+			Header:BytePos::DUMMY,
+			Unused:Vec::new(),
+			Stored:Vec::new(),
 			VarDefinitions:HashMap::new(),
-			ExportedVars:HashSet::new(),
+			Purity,
 			Inlined:false,
+			Edits:Vec::new(),
 		}
 	}
 
-	/// Performs a single pass of inlining on the given module,
-	/// setting `Inlined` to true if any inlining occurs.
-	fn Inline(&mut self, mut Module:Module) -> Module {
+	/// Performs a single run of inlining on the given module or script: the
+	/// whole program is planned from one analysis first, then rewritten in one
+	/// traversal, and the arrows this leaves immediately invoked are reduced,
+	/// setting `Inlined` to true if any inlining or reduction occurs.
+	///
+	/// When the `Store` option asks for it, stores no read observes are
+	/// removed before the plan, so the bindings they leave unread can go in
+	/// the same run.
+	fn Inline(&mut self, mut Program:Program) -> Program {
 		self.Inlined = false;
 
-		Module.visit_mut_with(self);
+		if self.Option.Store == Store::Remove {
+			let mut Sweeper = Sweeper::New(self.Purity, &Scanner::Scan(&Program, self.Purity));
 
-		Module
-	}
-}
+			Program.visit_mut_with(&mut Sweeper);
 
-impl<'a> VisitMut for Inliner<'a> {
-	/// Collects names of variables that are explicitly exported.
-	fn visit_mut_export_named_specifier(&mut self, Export:&mut ExportNamedSpecifier) {
-		if let ModuleExportName::Ident(Ident { sym, .. }) = &Export.orig {
-			self.ExportedVars.insert(sym.to_string());
+			self.Inlined |= !Sweeper.Swept.is_empty();
+
+			self.Edits.append(&mut Sweeper.Edits);
+
+			self.Stored.extend(Sweeper.Swept.iter().map(|Ident| {
+				Stored { Name:Ident.sym.to_string(), Line:self.Cm.lookup_char_pos(Ident.span.lo).line }
+			}));
+		}
+
+		self.Header = match &Program {
+			Program::Module(Module) => Module.body.first().map(|Item| Item.span().lo),
+			Program::Script(Script) => Script.body.first().map(|Stmt| Stmt.span().lo),
 		}
+		.unwrap_or(BytePos::DUMMY);
+
+		(self.Planned, self.Dead) = Planner::Plan(&Program, self.Option, self.Purity);
+
+		Program.visit_mut_with(self);
+
+		let mut Reducer = Reducer::New(self.Option, self.Purity);
+
+		Program.visit_mut_with(&mut Reducer);
+
+		self.Inlined |= Reducer.Reduced;
+
+		self.Edits.append(&mut Reducer.Edits);
+
+		Program.visit_mut_with(&mut Parenthesizer);
+
+		Program
 	}
 
-	/// Registers variable declarations for possible inlining, but only
-	/// if the variable isn't exported.
-	fn visit_mut_var_declarator(&mut self, Var:&mut VarDeclarator) {
-		if let Pat::Ident(BindingIdent { id, .. }) = &Var.name {
-			let Name:String = id.sym.to_string(); // Convert to String right away
+	/// Removes the planned variable declarators of a statement list, keeping
+	/// their initializers for the use site, then rewrites the remaining
+	/// entries.
+	///
+	/// Every statement list goes through here, so function, method, arrow,
+	/// block and namespace bodies are handled just like the top level.
+	/// Removal works declarator by declarator: the other declarators of a
+	/// statement and its `var`, `let` or `const` kind are kept, and the
+	/// statement is only dropped once it has no declarators left.
+	/// Properties of an object pattern go the same way, see
+	/// [`Self::Destructure`].
+	///
+	/// Function and class declarations the plan moves are dropped whole,
+	/// keeping the equivalent named expression for the use site.
+	///
+	/// Declarations nothing uses go the same way, except that an impure
+	/// initializer the `Dead` option keeps stays behind as an expression
+	/// statement. Each is reported in [`Self::Unused`].
+	///
+	/// Comments of a removed declaration follow the `Comment` option: they
+	/// move in front of the inlined initializer, or stay in front of the next
+	/// remaining entry. License and directive comments, and the header of the
+	/// file, always stay.
+	///
+	/// Every removal is also recorded as a text edit: a dropped statement
+	/// loses its whole text, a single declarator loses its text together with
+	/// the comma that separates it from its neighbour.
+	fn Eliminate<T>(&mut self, Items:&mut Vec<T>)
+	where
+		T: Item + VisitMutWith<Self>, {
+		let Comments = self.Purity.Comments;
+
+		let Policy = self.Option.Comment;
+
+		let mut Dropped = Vec::new();
+
+		for (Index, Item) in Items.iter_mut().enumerate() {
+			let Span = Item.span();
+
+			if let Some(Decl) = Item.Callable() {
+				if let Some(Ident) = Declared(Decl).filter(|Ident| self.Dead.contains(&Ident.to_id())) {
+					self.Report(Ident, Span);
+
+					self.Edits.push(Edit::Remove(Span));
+
+					Dropped.push((Index, Span, None));
+
+					continue;
+				}
+
+				let Planned =
+					Declared(Decl).map(|Ident| Ident.to_id()).filter(|Name| self.Planned.contains(Name));
 
-			if !self.ExportedVars.contains(&Name) {
-				// Only inline if not exported
-				if let Some(Init) = &Var.init {
-					self.VarDefinitions.insert(Name.clone(), (**Init).clone());
+				if let (Some(Name), Some(Init)) = (Planned, Expression(Decl)) {
+					self.Edits.push(Edit::Remove(Span));
 
-					self.VarUsage.insert(Name, 0);
+					Dropped.push((Index, Span, Some(Init.span().lo)));
+
+					self.VarDefinitions.insert(Name, Definition { Init, Type:None, Member:None });
 				}
+
+				continue;
 			}
-		}
 
-		Var.visit_mut_children_with(self);
-	}
+			let Some(VarDecl) = Item.VarMut() else {
+				continue;
+			};
+
+			if let [VarDeclarator { name: Pat::Ident(BindingIdent { id, .. }), init: Some(Init), .. }] =
+				&mut VarDecl.decls[..]
+				&& self.Dead.contains(&id.to_id()) && !self.Purity.Expr(Init)
+			{
+				self.Report(id, Span);
 
-	/// Attempts to inline variables used only once, but skips exported
-	/// variables.
-	fn visit_mut_expr(&mut self, Expr:&mut Expr, _Parent:&mut dyn VisitMutWith) {
-		match Expr {
-			Expr::Ident(Ident { sym, .. }) => {
-				let Name = sym.to_string();
+				let Init = Init.clone();
 
-				if !self.ExportedVars.contains(&Name) {
-					// Don't inline exported variables
-					if let Some(Count) = self.VarUsage.get_mut(&Name) {
-						*Count += 1;
+				self.Edits.push(Edit::Remove(Span::new(Span.lo, Init.span().lo)));
 
-						if let Some(Init) = self.VarDefinitions.get(&Name) {
-							if *Count == 1 {
-								*Expr = Init.clone();
+				*Item = T::Discard(Init, Span);
 
-								self.Inlined = true;
+				continue;
+			}
 
-								return;
-							}
-						}
+			let mut First = None;
+
+			let Spans = VarDecl.decls.iter().map(|Decl| Decl.span).collect::<Vec<_>>();
+
+			let Removed = VarDecl
+				.decls
+				.iter()
+				.map(|Decl| {
+					match &Decl.name {
+						Pat::Ident(BindingIdent { id, .. }) => {
+							self.Planned.contains(&id.to_id()) || self.Dead.contains(&id.to_id())
+						},
+						Pat::Object(Pattern) => Emptied(Pattern, &self.Planned),
+						_ => false,
 					}
+				})
+				.collect::<Vec<_>>();
+
+			VarDecl.decls.retain_mut(|Decl| {
+				let (Ident, Type) = match &mut Decl.name {
+					Pat::Ident(BindingIdent { id, type_ann }) => (&*id, type_ann),
+					Pat::Object(Pattern) => {
+						return match Decl.init.as_deref() {
+							Some(Init) if Source(Init) => self.Destructure(Pattern, Init),
+							_ => true,
+						};
+					},
+					_ => return true,
+				};
+
+				let Name = Ident.to_id();
+
+				if self.Dead.contains(&Name) {
+					self.Report(Ident, Decl.span);
+
+					return false;
 				}
-			},
-			_ => {},
-		}
 
-		Expr.visit_mut_children_with(self);
-	}
+				if !self.Planned.contains(&Name) {
+					return true;
+				}
+
+				let Type = Type.take().map(|Annotation| Annotation.type_ann);
+
+				if let Some(Init) = Decl.init.take() {
+					if let Some(Comments) = Comments {
+						let (Kept, Moved):(Vec<_>, Vec<_>) =
+							Detach(Comments, Decl.span).into_iter().partition(|Comment| {
+								Policy == Placement::Keep || Pinned(Comment, self.Header)
+							});
+
+						self.Edits.extend(Moved.iter().map(|Comment| Edit::Remove(Comment.span)));
+
+						Lead(Comments, Init.span().lo, Moved);
 
-	/// Removes variable declarations that are used only once and are not
-	/// exported.
-	fn visit_mut_module_items(&mut self, Items:&mut Vec<ModuleItem>) {
-		Items.retain(|Item| {
-			if let ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl))) = Item {
-				for Decl in &VarDecl.decls {
-					if let Pat::Ident(BindingIdent { id: Name, .. }) = Decl.name {
-						if self.VarUsage.get(&Name) == Some(&1)
-							&& !self.ExportedVars.contains(&Name)
-						{
-							self.Inlined = true;
-
-							return false; // Remove this declaration if not exported and used once
-						}
+						Lead(Comments, Span.lo, Kept);
 					}
+
+					First.get_or_insert(Init.span().lo);
+
+					self.VarDefinitions.insert(Name, Definition { Init:*Init, Type, Member:None });
 				}
+
+				false
+			});
+
+			if VarDecl.decls.is_empty() {
+				self.Edits.push(Edit::Remove(Span));
+
+				Dropped.push((Index, Span, First));
+
+				continue;
+			}
+
+			self.Edits.extend(Separated(&Spans, &Removed).map(Edit::Remove));
+		}
+
+		if Dropped.is_empty() {
+			for Item in Items.iter_mut() {
+				Item.visit_mut_with(self);
 			}
 
-			true
+			return;
+		}
+
+		let Gone = Dropped.iter().map(|(Index, ..)| *Index).collect::<HashSet<_>>();
+
+		if let Some(Comments) = Comments {
+			for (Index, Span, First) in &Dropped {
+				let (Kept, Moved):(Vec<_>, Vec<_>) = Detach(Comments, *Span)
+					.into_iter()
+					.partition(|Comment| {
+						Policy == Placement::Keep || Pinned(Comment, self.Header) || First.is_none()
+					});
+
+				if let Some(First) = First {
+					self.Edits.extend(Moved.iter().map(|Comment| Edit::Remove(Comment.span)));
+
+					Lead(Comments, *First, Moved);
+				}
+
+				let Next = (Index + 1..Items.len()).find(|Index| !Gone.contains(Index));
+
+				let Previous = (0..*Index).rev().find(|Index| !Gone.contains(Index));
+
+				match (Next, Previous, First) {
+					(Some(Next), ..) => Lead(Comments, Items[Next].span().lo, Kept),
+					(None, Some(Previous), _) => Trail(Comments, Items[Previous].span().hi, Kept),
+					(None, None, Some(First)) => Lead(Comments, *First, Kept),
+					(None, None, None) => {},
+				}
+			}
+		}
+
+		Prune(Items, |Index| Gone.contains(&Index));
+
+		for Item in Items.iter_mut() {
+			Item.visit_mut_with(self);
+		}
+	}
+
+	/// Records the removal of the unused declaration of `Ident` spanning
+	/// `Span`.
+	fn Report(&mut self, Ident:&Ident, Span:Span) {
+		self.Inlined = true;
+
+		self.Unused.push(Unused {
+			Name:Ident.sym.to_string(),
+			Line:self.Cm.lookup_char_pos(Span.lo).line,
 		});
 	}
+
+	/// Removes the planned properties of an object pattern over `Source`,
+	/// keeping a member access on `Source` for the use site of each, and
+	/// tells whether any property is left.
+	///
+	/// Every removed property is recorded as a text edit together with the
+	/// comma that separates it from its neighbour, unless the whole
+	/// declarator goes.
+	fn Destructure(&mut self, Pattern:&mut ObjectPat, Source:&Expr) -> bool {
+		let Moved = Properties(Pattern)
+			.into_iter()
+			.filter(|Property| self.Planned.contains(&Property.Name.to_id()))
+			.map(|Property| (Property.Index, Property.Name.to_id(), Property.Member))
+			.collect::<Vec<_>>();
+
+		if Moved.is_empty() {
+			return true;
+		}
+
+		let Spans = Pattern.props.iter().map(|Prop| Prop.span()).collect::<Vec<_>>();
+
+		let mut Removed = vec![false; Spans.len()];
+
+		for (Index, Name, Member) in Moved {
+			Removed[Index] = true;
+
+			self.VarDefinitions.insert(
+				Name,
+				Definition {
+					Member:Some(Text(&Member)),
+					Init:Destructure::Member(Source, Member, Source.span()),
+					Type:None,
+				},
+			);
+		}
+
+		if !Removed.contains(&false) {
+			Pattern.props.clear();
+
+			return false;
+		}
+
+		self.Edits.extend(Separated(&Spans, &Removed).map(Edit::Remove));
+
+		Prune(&mut Pattern.props, |Index| Removed[Index]);
+
+		true
+	}
+
+	/// The expression replacing `Ident` when it is the single use of a
+	/// removed declaration: its initializer, parenthesized until
+	/// [`Parenthesizer`] sees whether its new parent needs it, and followed
+	/// by the declaration's type annotation as the `Annotation` option asks.
+	/// A destructured property becomes the member access it stands for.
+	fn Substitute(
+		&mut self,
+		Ident:&Ident,
+		Key:std::option::Option<String>,
+	) -> std::option::Option<Expr> {
+		let Definition { mut Init, Type, Member } = self.VarDefinitions.remove(&Ident.to_id())?;
+
+		self.Inlined = true;
+
+		// The source keeps its span, so a later pass inlining it edits the
+		// text of the access as well.
+		if let (Some(Member), Expr::Member(Access)) = (&Member, &mut Init) {
+			self.Edits.push(Edit::Replace {
+				Span:Ident.span,
+				Init:Access.obj.span(),
+				Key,
+				Member:Some(Member.clone()),
+				Type:None,
+			});
+
+			Access.span = Ident.span;
+
+			return Some(Wrap(Init));
+		}
+
+		self.Edits.push(Edit::Replace {
+			Span:Ident.span,
+			Init:Init.span(),
+			Key,
+			Member:None,
+			Type:Type.as_ref().map(|Type| (Keyword(self.Option.Annotation), Type.span())),
+		});
+
+		Some(Annotate(self.Option.Annotation, Init, Type, Ident.span))
+	}
+}
+
+/// The text to delete for each removed entry of a comma-separated list whose
+/// entries span `Spans`, with at least one entry left: the entry together
+/// with the comma after it, or with the comma before it when no later entry
+/// stays.
+fn Separated<'a>(Spans:&'a [Span], Removed:&'a [bool]) -> impl Iterator<Item = Span> + 'a {
+	(0..Spans.len()).filter(|Index| Removed[*Index]).map(|Index| {
+		if Removed[Index + 1..].contains(&false) {
+			Span::new(Spans[Index].lo, Spans[Index + 1].lo)
+		} else {
+			Span::new(Spans[Index - 1].hi, Spans[Index].hi)
+		}
+	})
+}
+
+/// Drops the entries of `Items` at the indices `Gone` holds, keeping the
+/// others in order.
+fn Prune<T>(Items:&mut Vec<T>, Gone:impl Fn(usize) -> bool) {
+	let mut Index = 0;
+
+	Items.retain(|_| {
+		Index += 1;
+
+		!Gone(Index - 1)
+	});
+}
+
+/// The keyword a type annotation is kept with at the use site.
+fn Keyword(Annotation:Annotation) -> &'static str {
+	match Annotation {
+		Annotation::As => "as",
+		_ => "satisfies",
+	}
+}
+
+/// `Init` moved to a use site, parenthesized until [`Parenthesizer`] sees
+/// whether its new parent needs it, and followed by `Type` as the
+/// `Annotation` option asks. `Span` is the span of the use.
+fn Annotate(
+	Annotation:Annotation,
+	Init:Expr,
+	Type:std::option::Option<Box<TsType>>,
+	Span:Span,
+) -> Expr {
+	let Init = Box::new(Wrap(Init));
+
+	match Type {
+		Some(Type) if Annotation == Annotation::As => {
+			Wrap(Expr::TsAs(TsAsExpr { span:Span, expr:Init, type_ann:Type }))
+		},
+		Some(Type) => {
+			Wrap(Expr::TsSatisfies(TsSatisfiesExpr { span:Span, expr:Init, type_ann:Type }))
+		},
+		None => *Init,
+	}
+}
+
+/// An initializer taken from a removed declaration, waiting for its single
+/// use.
+struct Definition {
+	/// The initializer.
+	Init:Expr,
+	/// The type the declaration was annotated with.
+	Type:std::option::Option<Box<TsType>>,
+	/// The member read on the initializer when it stands in for a
+	/// destructured property, as `.a` for `props.a`.
+	Member:std::option::Option<String>,
+}
+
+impl<'a> VisitMut for Inliner<'a> {
+	/// Replaces the single use of a removed declaration with its initializer.
+	fn visit_mut_expr(&mut self, Expr:&mut Expr) {
+		if let Expr::Ident(Ident) = Expr
+			&& let Some(Substitute) = self.Substitute(Ident, None)
+		{
+			*Expr = Substitute;
+
+			// The initializer may itself use bindings waiting to be inlined.
+			return self.visit_mut_expr(Expr);
+		}
+
+		Expr.visit_mut_children_with(self);
+	}
+
+	/// Replaces a shorthand property such as `{ x }` that is the single use
+	/// of a removed declaration with `{ x: init }`.
+	fn visit_mut_prop(&mut self, Prop:&mut Prop) {
+		if let Prop::Shorthand(Ident) = Prop {
+			let Ident = Ident.clone();
+
+			if let Some(mut Value) = self.Substitute(&Ident, Some(Ident.sym.to_string())) {
+				let Key = IdentName::new(Ident.sym, Ident.span);
+
+				self.visit_mut_expr(&mut Value);
+
+				*Prop = Prop::KeyValue(KeyValueProp { key:PropName::Ident(Key), value:Box::new(Value) });
+
+				return;
+			}
+		}
+
+		Prop.visit_mut_children_with(self);
+	}
+
+	fn visit_mut_module_items(&mut self, Items:&mut Vec<ModuleItem>) { self.Eliminate(Items); }
+
+	fn visit_mut_stmts(&mut self, Stmts:&mut Vec<Stmt>) { self.Eliminate(Stmts); }
 }
 
 use std::{
 	collections::{HashMap, HashSet},
 	fs,
+	hash::{DefaultHasher, Hash, Hasher},
 	io::{self},
 	path::Path,
 	rc::Rc,
 };
 
-use rayon::prelude::*;
-use swc_common::{FileName, SourceMap};
+use swc_common::{
+	BytePos,
+	FileName,
+	GLOBALS,
+	Globals,
+	Mark,
+	SourceMap,
+	Span,
+	Spanned,
+	SyntaxContext,
+	comments::SingleThreadedComments,
+};
 use swc_ecma_ast::*;
 use swc_ecma_codegen::{Config, Emitter, text_writer::JsWriter};
-use swc_ecma_parser::{Parser, StringInput, Syntax, lexer::Lexer};
-use swc_ecma_visit::{VisitMut, VisitMutWith};
-use tempfile::NamedTempFile;
+use swc_ecma_parser::{Parser, StringInput, lexer::Lexer};
+use swc_ecma_transforms_base::resolver;
+use swc_ecma_visit::{VisitMut, VisitMutWith, VisitWith};
+
+use crate::Struct::Eliminate::{
+	Diagnostic::{Bailout, Convergence, Removed as Unused, Store as Stored, Struct as Diagnostic},
+	Option::{Annotation, Comment as Placement, Store, Struct as Option},
+};
+
+use self::{
+	Comment::{Detach, Lead, Pinned, Trail},
+	Destructure::{Emptied, Properties, Source, Text},
+	Dynamic::Guard,
+	Effect::Purity,
+	Grammar::{Parse, Select},
+	Layout::Style,
+	Liveness::{Scanner, Sweeper},
+	Paren::{Parenthesizer, Wrap},
+	Plan::Planner,
+	Reduce::Reducer,
+	Statement::{Declared, Expression, Item},
+	Text::{Edit, Editor, Marker},
+};
 
+mod Analysis;
+mod Comment;
+mod Destructure;
+mod Dynamic;
+mod Effect;
+mod Export;
+mod Grammar;
+mod Layout;
+mod Liveness;
+mod Paren;
+mod Plan;
+mod Reduce;
+mod Statement;
+mod Text;
+
+#[cfg(test)]
 mod Test;
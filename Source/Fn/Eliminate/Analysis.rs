@@ -0,0 +1,723 @@
+/// Kind of construct that changes when, or how many times, the code inside it
+/// runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Boundary {
+	/// Function bodies, parameter defaults, getters, class field
+	/// initializers and static blocks, which run later or repeatedly.
+	Function,
+	/// Bodies of function declarations, which are hoisted and so may also run
+	/// before the code that precedes them.
+	Hoisted,
+	/// Loop tests, updates and bodies, which run repeatedly.
+	Loop,
+	/// Code that may not run at all, such as `if` branches, `?:`, the right
+	/// side of `&&`, `||` and `??`, optional chains, `try` blocks and pattern
+	/// defaults.
+	Branch,
+}
+
+/// The constructs enclosing a point in the module, from the outermost
+/// inwards, each tagged with a unique index.
+pub type Context = Vec<(usize, Boundary)>;
+
+/// A read of a binding together with the constructs that enclose it.
+#[derive(Clone, Debug)]
+pub struct Reference {
+	/// Span of the identifier.
+	pub Span:Span,
+	/// Constructs enclosing the read.
+	pub Context:Context,
+	/// Whether the read is the callee of a call or `new`, where the value
+	/// goes nowhere else.
+	pub Callee:bool,
+	/// Whether the read is called or tags a template, where a member access
+	/// would pass its object as `this`.
+	pub Bound:bool,
+	/// Position of the reference among every read and plain assignment of
+	/// the program, in traversal order.
+	pub Order:usize,
+}
+
+/// Usage facts collected for a single binding before any rewriting happens.
+#[derive(Clone, Debug, Default)]
+pub struct Binding {
+	/// Number of times the binding is read.
+	pub Read:usize,
+	/// Number of times the binding is written after its declaration,
+	/// including compound and destructuring assignments, updates and `for`
+	/// heads.
+	pub Write:usize,
+	/// Number of declarations that introduce the binding, counting `var`
+	/// re-declarations, parameters and function declarations.
+	pub Declaration:usize,
+	/// Number of reads that name a JSX element, as in `<C />`, where nothing
+	/// but an identifier can stand in for the binding.
+	pub Tagged:usize,
+	/// Span of the first declaration.
+	pub Span:Span,
+	/// Whether the binding holds its value before any code of its scope
+	/// runs, as function declarations and imports do. Any other binding is
+	/// `undefined`, or in its temporal dead zone, until its declaration runs.
+	pub Hoisted:bool,
+	/// Number of references from type positions, such as `typeof x` or a
+	/// class named in a type annotation.
+	pub Typed:usize,
+	/// Every read, in traversal order.
+	pub Position:Vec<Reference>,
+	/// Every plain assignment `x = ...`, in traversal order, with the span
+	/// of its target.
+	pub Assigned:Vec<Reference>,
+	/// Constructs enclosing the first declaration.
+	pub Context:Context,
+}
+
+impl Binding {
+	/// The boundaries crossed between the declaration and the given read, or
+	/// `None` when the read does not run inside the declaration's context at
+	/// all, as with a `var` declared in a loop and read after it.
+	pub fn Crossed<'a>(&self, Reference:&'a Reference) -> Option<&'a [(usize, Boundary)]> {
+		Reference.Context.strip_prefix(&self.Context[..])
+	}
+
+	/// Whether the binding is declared inside a function, where no other
+	/// script can reach it as a global.
+	pub fn Nested(&self) -> bool {
+		self.Context.iter().any(|(_, Boundary)| matches!(Boundary, Boundary::Function | Boundary::Hoisted))
+	}
+}
+
+/// The identifier a possibly qualified type name starts with.
+fn Leftmost(Name:&TsEntityName) -> &Ident {
+	match Name {
+		TsEntityName::Ident(Ident) => Ident,
+		TsEntityName::TsQualifiedName(Qualified) => Leftmost(&Qualified.left),
+	}
+}
+
+/// Read-only visitor that computes complete reference counts, write counts and
+/// positions for every binding in a module.
+#[derive(Default)]
+pub struct Analyzer {
+	/// Usage facts keyed by each binding's resolved `Id`.
+	pub Binding:HashMap<Id, Binding>,
+	/// Tracks which bindings are exported and should not be inlined.
+	pub Exported:HashSet<Id>,
+	/// Bindings of scopes where `eval`, `with` or `arguments` can reach
+	/// them by name, which should not be inlined either.
+	pub Dynamic:HashSet<Id>,
+	/// Constructs enclosing the node being visited.
+	Context:Context,
+	/// Index given to the next construct entered.
+	Next:usize,
+	/// Order given to the next read or plain assignment.
+	Order:usize,
+}
+
+impl Analyzer {
+	/// Runs the analysis over the given module or script without modifying
+	/// it.
+	///
+	/// Exports and scopes reached by name at runtime are collected by
+	/// separate pre-scans, so a binding is protected no matter where its
+	/// export or the `eval` appears.
+	pub fn Analyze(Program:&Program) -> Self {
+		let mut Analyzer = Self {
+			Exported:Exporter::Collect(Program),
+			Dynamic:Guard::Scan(Program).Dynamic,
+			..Self::default()
+		};
+
+		Program.visit_with(&mut Analyzer);
+
+		Analyzer
+	}
+
+	/// A reference to the given identifier at the current point.
+	fn Reference(&mut self, Ident:&Ident) -> Reference {
+		self.Order += 1;
+
+		Reference {
+			Span:Ident.span,
+			Context:self.Context.clone(),
+			Callee:false,
+			Bound:false,
+			Order:self.Order,
+		}
+	}
+
+	/// Records a read of the given identifier.
+	fn Read(&mut self, Ident:&Ident) {
+		let Reference = self.Reference(Ident);
+
+		let Binding = self.Binding.entry(Ident.to_id()).or_default();
+
+		Binding.Read += 1;
+
+		Binding.Position.push(Reference);
+	}
+
+	/// Records a plain assignment `x = ...` to the given identifier.
+	fn Assigned(&mut self, Ident:&Ident) {
+		let Reference = self.Reference(Ident);
+
+		self.Write(Ident);
+
+		self.Binding.entry(Ident.to_id()).or_default().Assigned.push(Reference);
+	}
+
+	/// Records the callee of a call, `new` or optional call, or the tag of a
+	/// template. `Direct` is set for a call or `new`, and `Bound` for
+	/// anything but `new`.
+	fn Callee(&mut self, Callee:&Expr, Direct:bool, Bound:bool) {
+		let Expr::Ident(Ident) = Callee.unwrap_parens() else {
+			return Callee.visit_with(self);
+		};
+
+		self.Read(Ident);
+
+		if let Some(Reference) =
+			self.Binding.get_mut(&Ident.to_id()).and_then(|Binding| Binding.Position.last_mut())
+		{
+			Reference.Callee = Direct;
+
+			Reference.Bound = Bound;
+		}
+	}
+
+	/// Records a read of the identifier naming a JSX element.
+	fn Tag(&mut self, Ident:&Ident) {
+		self.Read(Ident);
+
+		self.Binding.entry(Ident.to_id()).or_default().Tagged += 1;
+	}
+
+	/// Runs `Inner` inside a construct of the given kind.
+	fn Enter(&mut self, Boundary:Boundary, Inner:impl FnOnce(&mut Self)) {
+		self.Context.push((self.Next, Boundary));
+
+		self.Next += 1;
+
+		Inner(self);
+
+		self.Context.pop();
+	}
+
+	/// Records a write to the given identifier.
+	fn Write(&mut self, Ident:&Ident) { self.Binding.entry(Ident.to_id()).or_default().Write += 1; }
+
+	/// Records a declaration of the given identifier.
+	fn Declare(&mut self, Ident:&Ident) {
+		let Binding = self.Binding.entry(Ident.to_id()).or_default();
+
+		if Binding.Declaration == 0 {
+			Binding.Context = self.Context.clone();
+
+			Binding.Span = Ident.span;
+		}
+
+		Binding.Declaration += 1;
+	}
+
+	/// Records a declaration of the given identifier that is hoisted along
+	/// with its value.
+	fn Hoist(&mut self, Ident:&Ident) {
+		self.Declare(Ident);
+
+		self.Binding.entry(Ident.to_id()).or_default().Hoisted = true;
+	}
+
+	/// Records a function, whose body runs inside a construct of the given
+	/// kind.
+	fn Function(&mut self, Function:&Function, Boundary:Boundary) {
+		Function.decorators.visit_with(self);
+
+		self.Enter(Boundary, |This| {
+			Function.type_params.visit_with(This);
+
+			Function.params.visit_with(This);
+
+			Function.return_type.visit_with(This);
+
+			Function.body.visit_with(This);
+		});
+	}
+
+	/// Records a write to the given identifier, preceded by a read when `Read`
+	/// is set.
+	fn Store(&mut self, Ident:&Ident, Read:bool) {
+		if Read {
+			self.Read(Ident);
+		}
+
+		self.Write(Ident);
+	}
+
+	/// Records a write through an assignment target expression, such as
+	/// `(x)`, `x!` or `x as T`, and a read as well when `Read` is set.
+	/// Member targets only read their object.
+	fn Written(&mut self, Expr:&Expr, Read:bool) {
+		match Expr {
+			Expr::Ident(Ident) => self.Store(Ident, Read),
+			Expr::Paren(ParenExpr { expr, .. })
+			| Expr::TsAs(TsAsExpr { expr, .. })
+			| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| Expr::TsNonNull(TsNonNullExpr { expr, .. })
+			| Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+			| Expr::TsInstantiation(TsInstantiation { expr, .. }) => self.Written(expr, Read),
+			_ => Expr.visit_with(self),
+		}
+	}
+
+	/// Records the target of an assignment, treating compound operators such
+	/// as `+=` and `||=` as a read followed by a write.
+	fn Target(&mut self, Target:&SimpleAssignTarget, Read:bool) {
+		match Target {
+			SimpleAssignTarget::Ident(BindingIdent { id, .. }) => self.Store(id, Read),
+			SimpleAssignTarget::Paren(ParenExpr { expr, .. })
+			| SimpleAssignTarget::TsAs(TsAsExpr { expr, .. })
+			| SimpleAssignTarget::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| SimpleAssignTarget::TsNonNull(TsNonNullExpr { expr, .. })
+			| SimpleAssignTarget::TsTypeAssertion(TsTypeAssertion { expr, .. })
+			| SimpleAssignTarget::TsInstantiation(TsInstantiation { expr, .. }) => {
+				self.Written(expr, Read)
+			},
+			_ => Target.visit_children_with(self),
+		}
+	}
+
+	/// Records a write of every identifier bound by a destructuring
+	/// assignment pattern, visiting default values and computed keys as
+	/// reads.
+	fn Assign(&mut self, Pat:&Pat) {
+		match Pat {
+			Pat::Ident(BindingIdent { id, .. }) => self.Write(id),
+			Pat::Array(ArrayPat { elems, .. }) => self.Elements(elems),
+			Pat::Object(ObjectPat { props, .. }) => self.Properties(props),
+			Pat::Rest(RestPat { arg, .. }) => self.Assign(arg),
+			Pat::Assign(AssignPat { left, right, .. }) => {
+				self.Assign(left);
+
+				self.Enter(Boundary::Branch, |This| right.visit_with(This));
+			},
+			Pat::Expr(Expr) => self.Written(Expr, false),
+			Pat::Invalid(_) => {},
+		}
+	}
+
+	/// Records the elements of an array assignment pattern.
+	fn Elements(&mut self, Elems:&[Option<Pat>]) {
+		for Elem in Elems.iter().flatten() {
+			self.Assign(Elem);
+		}
+	}
+
+	/// Records the properties of an object assignment pattern.
+	fn Properties(&mut self, Props:&[ObjectPatProp]) {
+		for Prop in Props {
+			match Prop {
+				ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+					key.visit_with(self);
+
+					self.Assign(value);
+				},
+				ObjectPatProp::Assign(AssignPatProp { key, value, .. }) => {
+					self.Write(&key.id);
+
+					if let Some(Value) = value {
+						self.Enter(Boundary::Branch, |This| Value.visit_with(This));
+					}
+				},
+				ObjectPatProp::Rest(RestPat { arg, .. }) => self.Assign(arg),
+			}
+		}
+	}
+
+	/// Records the head of a `for-in` or `for-of` loop, which writes its
+	/// bindings on every iteration.
+	fn Head(&mut self, Head:&ForHead) {
+		match Head {
+			ForHead::VarDecl(VarDecl) => {
+				VarDecl.visit_with(self);
+
+				for Decl in &VarDecl.decls {
+					for Ident in Bindings(&Decl.name) {
+						self.Write(Ident);
+					}
+				}
+			},
+			ForHead::UsingDecl(UsingDecl) => UsingDecl.visit_with(self),
+			ForHead::Pat(Pat) => self.Assign(Pat),
+		}
+	}
+}
+
+impl Visit for Analyzer {
+	/// Counts every identifier in expression position as a read.
+	fn visit_expr(&mut self, Expr:&Expr) {
+		match Expr {
+			Expr::Ident(Ident) => self.Read(Ident),
+			_ => Expr.visit_children_with(self),
+		}
+	}
+
+	fn visit_call_expr(&mut self, Call:&CallExpr) {
+		match &Call.callee {
+			Callee::Expr(Callee) => self.Callee(Callee, true, true),
+			Callee => Callee.visit_with(self),
+		}
+
+		Call.type_args.visit_with(self);
+
+		Call.args.visit_with(self);
+	}
+
+	fn visit_new_expr(&mut self, New:&NewExpr) {
+		self.Callee(&New.callee, true, false);
+
+		New.type_args.visit_with(self);
+
+		New.args.visit_with(self);
+	}
+
+	fn visit_opt_call(&mut self, Call:&OptCall) {
+		self.Callee(&Call.callee, false, true);
+
+		Call.type_args.visit_with(self);
+
+		Call.args.visit_with(self);
+	}
+
+	fn visit_tagged_tpl(&mut self, Tagged:&TaggedTpl) {
+		self.Callee(&Tagged.tag, false, true);
+
+		Tagged.type_params.visit_with(self);
+
+		Tagged.tpl.visit_with(self);
+	}
+
+	/// Counts the binding a type refers to, as in `typeof x`,
+	/// `keyof typeof x`, `x.T` or `import A = x.B`, which keeps a value
+	/// binding alive for the type checker and for decorator metadata.
+	fn visit_ts_entity_name(&mut self, Name:&TsEntityName) {
+		self.Binding.entry(Leftmost(Name).to_id()).or_default().Typed += 1;
+	}
+
+	/// Counts the component a JSX element names, as in `<C />`, as a read.
+	/// Lowercase names such as `div` are intrinsic elements, not bindings.
+	fn visit_jsx_element_name(&mut self, Name:&JSXElementName) {
+		match Name {
+			JSXElementName::Ident(Ident) if !Ident.sym.starts_with(|Char:char| Char.is_ascii_lowercase()) => {
+				self.Tag(Ident)
+			},
+			JSXElementName::Ident(_) => {},
+			_ => Name.visit_children_with(self),
+		}
+	}
+
+	/// Counts the object of a JSX member name, as `ui` in `<ui.Button />`, as
+	/// a read.
+	fn visit_jsx_object(&mut self, Object:&JSXObject) {
+		match Object {
+			JSXObject::Ident(Ident) => self.Tag(Ident),
+			JSXObject::JSXMemberExpr(Member) => Member.obj.visit_with(self),
+		}
+	}
+
+	/// Skips the name of a closing tag, which repeats the opening one.
+	fn visit_jsx_closing_element(&mut self, _:&JSXClosingElement) {}
+
+	/// Counts shorthand properties such as `{ x }` as a read of `x`.
+	fn visit_prop(&mut self, Prop:&Prop) {
+		match Prop {
+			Prop::Shorthand(Ident) => self.Read(Ident),
+			_ => Prop.visit_children_with(self),
+		}
+	}
+
+	/// Counts every binding identifier reached outside an assignment as a
+	/// declaration: variables, parameters and `catch` bindings.
+	fn visit_binding_ident(&mut self, Binding:&BindingIdent) {
+		self.Declare(&Binding.id);
+
+		Binding.type_ann.visit_with(self);
+	}
+
+	fn visit_fn_decl(&mut self, Fn:&FnDecl) {
+		self.Hoist(&Fn.ident);
+
+		self.Function(&Fn.function, Boundary::Hoisted);
+	}
+
+	fn visit_class_decl(&mut self, Class:&ClassDecl) {
+		self.Declare(&Class.ident);
+
+		Class.class.visit_with(self);
+	}
+
+	fn visit_import_named_specifier(&mut self, Import:&ImportNamedSpecifier) {
+		self.Hoist(&Import.local);
+	}
+
+	fn visit_import_default_specifier(&mut self, Import:&ImportDefaultSpecifier) {
+		self.Hoist(&Import.local);
+	}
+
+	fn visit_import_star_as_specifier(&mut self, Import:&ImportStarAsSpecifier) {
+		self.Hoist(&Import.local);
+	}
+
+	/// Counts plain, compound and destructuring assignments as writes,
+	/// keeping the position of every plain assignment to a name. The
+	/// right-hand side of a destructuring assignment runs before its
+	/// defaults, so it is visited first.
+	fn visit_assign_expr(&mut self, Assign:&AssignExpr) {
+		let Target = match &Assign.left {
+			AssignTarget::Simple(Target) => Target,
+			AssignTarget::Pat(Pat) => {
+				Assign.right.visit_with(self);
+
+				return match Pat {
+					AssignTargetPat::Array(ArrayPat { elems, .. }) => self.Elements(elems),
+					AssignTargetPat::Object(ObjectPat { props, .. }) => self.Properties(props),
+					AssignTargetPat::Invalid(_) => {},
+				};
+			},
+		};
+
+		match Target {
+			SimpleAssignTarget::Ident(BindingIdent { id, .. }) if Assign.op == AssignOp::Assign => {
+				self.Assigned(id)
+			},
+			Target => self.Target(Target, Assign.op != AssignOp::Assign),
+		}
+
+		match Assign.op {
+			AssignOp::AndAssign | AssignOp::OrAssign | AssignOp::NullishAssign => {
+				self.Enter(Boundary::Branch, |This| Assign.right.visit_with(This))
+			},
+			_ => Assign.right.visit_with(self),
+		}
+	}
+
+	/// Visits the initializer before the pattern, whose defaults run after
+	/// it.
+	fn visit_var_declarator(&mut self, Declarator:&VarDeclarator) {
+		Declarator.init.visit_with(self);
+
+		Declarator.name.visit_with(self);
+	}
+
+	/// Counts `x++` and `--x` as both a read and a write of `x`.
+	fn visit_update_expr(&mut self, Update:&UpdateExpr) { self.Written(&Update.arg, true); }
+
+	fn visit_for_in_stmt(&mut self, For:&ForInStmt) {
+		For.right.visit_with(self);
+
+		self.Enter(Boundary::Loop, |This| {
+			This.Head(&For.left);
+
+			For.body.visit_with(This);
+		});
+	}
+
+	fn visit_for_of_stmt(&mut self, For:&ForOfStmt) {
+		For.right.visit_with(self);
+
+		self.Enter(Boundary::Loop, |This| {
+			This.Head(&For.left);
+
+			For.body.visit_with(This);
+		});
+	}
+
+	fn visit_for_stmt(&mut self, For:&ForStmt) {
+		For.init.visit_with(self);
+
+		self.Enter(Boundary::Loop, |This| {
+			For.test.visit_with(This);
+
+			For.update.visit_with(This);
+
+			For.body.visit_with(This);
+		});
+	}
+
+	fn visit_while_stmt(&mut self, While:&WhileStmt) {
+		self.Enter(Boundary::Loop, |This| {
+			While.test.visit_with(This);
+
+			While.body.visit_with(This);
+		});
+	}
+
+	fn visit_do_while_stmt(&mut self, While:&DoWhileStmt) {
+		self.Enter(Boundary::Loop, |This| {
+			While.body.visit_with(This);
+
+			While.test.visit_with(This);
+		});
+	}
+
+	fn visit_function(&mut self, Function:&Function) { self.Function(Function, Boundary::Function); }
+
+	fn visit_arrow_expr(&mut self, Arrow:&ArrowExpr) {
+		self.Enter(Boundary::Function, |This| {
+			Arrow.type_params.visit_with(This);
+
+			Arrow.params.visit_with(This);
+
+			Arrow.return_type.visit_with(This);
+
+			Arrow.body.visit_with(This);
+		});
+	}
+
+	fn visit_constructor(&mut self, Constructor:&Constructor) {
+		Constructor.key.visit_with(self);
+
+		self.Enter(Boundary::Function, |This| {
+			Constructor.params.visit_with(This);
+
+			Constructor.body.visit_with(This);
+		});
+	}
+
+	fn visit_getter_prop(&mut self, Getter:&GetterProp) {
+		Getter.key.visit_with(self);
+
+		self.Enter(Boundary::Function, |This| {
+			Getter.type_ann.visit_with(This);
+
+			Getter.body.visit_with(This);
+		});
+	}
+
+	fn visit_setter_prop(&mut self, Setter:&SetterProp) {
+		Setter.key.visit_with(self);
+
+		self.Enter(Boundary::Function, |This| {
+			Setter.this_param.visit_with(This);
+
+			Setter.param.visit_with(This);
+
+			Setter.body.visit_with(This);
+		});
+	}
+
+	fn visit_class_prop(&mut self, Prop:&ClassProp) {
+		Prop.decorators.visit_with(self);
+
+		Prop.key.visit_with(self);
+
+		Prop.type_ann.visit_with(self);
+
+		self.Enter(Boundary::Function, |This| Prop.value.visit_with(This));
+	}
+
+	fn visit_private_prop(&mut self, Prop:&PrivateProp) {
+		Prop.decorators.visit_with(self);
+
+		Prop.type_ann.visit_with(self);
+
+		self.Enter(Boundary::Function, |This| Prop.value.visit_with(This));
+	}
+
+	fn visit_static_block(&mut self, Block:&StaticBlock) {
+		self.Enter(Boundary::Function, |This| Block.body.visit_with(This));
+	}
+
+	fn visit_if_stmt(&mut self, If:&IfStmt) {
+		If.test.visit_with(self);
+
+		self.Enter(Boundary::Branch, |This| {
+			If.cons.visit_with(This);
+
+			If.alt.visit_with(This);
+		});
+	}
+
+	fn visit_cond_expr(&mut self, Cond:&CondExpr) {
+		Cond.test.visit_with(self);
+
+		self.Enter(Boundary::Branch, |This| {
+			Cond.cons.visit_with(This);
+
+			Cond.alt.visit_with(This);
+		});
+	}
+
+	fn visit_bin_expr(&mut self, Bin:&BinExpr) {
+		match Bin.op {
+			BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+				Bin.left.visit_with(self);
+
+				self.Enter(Boundary::Branch, |This| Bin.right.visit_with(This));
+			},
+			_ => Bin.visit_children_with(self),
+		}
+	}
+
+	fn visit_switch_stmt(&mut self, Switch:&SwitchStmt) {
+		Switch.discriminant.visit_with(self);
+
+		self.Enter(Boundary::Branch, |This| Switch.cases.visit_with(This));
+	}
+
+	fn visit_try_stmt(&mut self, Try:&TryStmt) {
+		self.Enter(Boundary::Branch, |This| {
+			Try.block.visit_with(This);
+
+			Try.handler.visit_with(This);
+
+			Try.finalizer.visit_with(This);
+		});
+	}
+
+	fn visit_opt_chain_expr(&mut self, Chain:&OptChainExpr) {
+		self.Enter(Boundary::Branch, |This| Chain.base.visit_with(This));
+	}
+
+	/// Defaults in declaration patterns only run for missing values.
+	fn visit_assign_pat(&mut self, Assign:&AssignPat) {
+		Assign.left.visit_with(self);
+
+		self.Enter(Boundary::Branch, |This| Assign.right.visit_with(This));
+	}
+
+	fn visit_assign_pat_prop(&mut self, Assign:&AssignPatProp) {
+		Assign.key.visit_with(self);
+
+		self.Enter(Boundary::Branch, |This| Assign.value.visit_with(This));
+	}
+}
+
+/// Collects every identifier bound by a declaration pattern.
+pub fn Bindings(Pat:&Pat) -> Vec<&Ident> {
+	match Pat {
+		Pat::Ident(BindingIdent { id, .. }) => vec![id],
+		Pat::Array(ArrayPat { elems, .. }) => elems.iter().flatten().flat_map(Bindings).collect(),
+		Pat::Object(ObjectPat { props, .. }) => {
+			props
+				.iter()
+				.flat_map(|Prop| {
+					match Prop {
+						ObjectPatProp::KeyValue(KeyValuePatProp { value, .. }) => Bindings(value),
+						ObjectPatProp::Assign(AssignPatProp { key, .. }) => vec![&key.id],
+						ObjectPatProp::Rest(RestPat { arg, .. }) => Bindings(arg),
+					}
+				})
+				.collect()
+		},
+		Pat::Rest(RestPat { arg, .. }) => Bindings(arg),
+		Pat::Assign(AssignPat { left, .. }) => Bindings(left),
+		Pat::Expr(_) | Pat::Invalid(_) => Vec::new(),
+	}
+}
+
+use std::collections::{HashMap, HashSet};
+
+use swc_common::Span;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use super::{Dynamic::Guard, Export::Exporter};
@@ -0,0 +1,53 @@
+/// Takes every comment attached before or after `Span`.
+pub fn Detach(Comments:&dyn Comments, Span:Span) -> Vec<Comment> {
+	let mut Taken = Comments.take_leading(Span.lo).unwrap_or_default();
+
+	Taken.extend(Comments.take_trailing(Span.hi).unwrap_or_default());
+
+	Taken
+}
+
+/// Attaches comments before `Position`, ahead of the ones already there.
+pub fn Lead(Comments:&dyn Comments, Position:BytePos, Taken:Vec<Comment>) {
+	if Taken.is_empty() {
+		return;
+	}
+
+	let Existing = Comments.take_leading(Position);
+
+	Comments.add_leading_comments(Position, Taken);
+
+	if let Some(Existing) = Existing {
+		Comments.add_leading_comments(Position, Existing);
+	}
+}
+
+/// Attaches comments after `Position`, behind the ones already there.
+pub fn Trail(Comments:&dyn Comments, Position:BytePos, Taken:Vec<Comment>) {
+	if !Taken.is_empty() {
+		Comments.add_trailing_comments(Position, Taken);
+	}
+}
+
+/// Tools that read a comment as a directive about the code that follows it.
+const DIRECTIVE:[&str; 3] = ["@ts-", "eslint-", "prettier-ignore"];
+
+/// Whether the comment must stay where it is: a license or otherwise marked
+/// for preservation, as with `/*! ... */`, `@license` and `@preserve`, a
+/// directive such as `// @ts-expect-error` that applies to the next line, or
+/// part of the header in front of `Header`, the first entry of the program.
+pub fn Pinned(Comment:&Comment, Header:BytePos) -> bool {
+	let Text = Comment.text.trim_start_matches(|Char:char| Char == '*' || Char.is_whitespace());
+
+	Comment.text.starts_with('!')
+		|| Comment.text.contains("@license")
+		|| Comment.text.contains("@preserve")
+		|| DIRECTIVE.iter().any(|Directive| Text.starts_with(Directive))
+		|| Comment.span.hi <= Header
+}
+
+use swc_common::{
+	BytePos,
+	Span,
+	comments::{Comment, Comments},
+};
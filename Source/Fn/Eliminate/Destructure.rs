@@ -0,0 +1,102 @@
+/// A property of an object pattern that binds a plain name, as `a` in
+/// `const { a, b: c } = props`, and can be read as a member of the source
+/// instead.
+pub struct Property<'a> {
+	/// Position of the property in the pattern.
+	pub Index:usize,
+	/// The bound name.
+	pub Name:&'a Ident,
+	/// The member of the source the name is bound to.
+	pub Member:MemberProp,
+}
+
+/// Whether the initializer of a destructuring declaration can be read again
+/// at the use site of a property: a plain identifier or `this`.
+pub fn Source(Init:&Expr) -> bool { matches!(Init, Expr::Ident(_) | Expr::This(_)) }
+
+/// The properties of the pattern that can each become a member access on the
+/// source, or nothing when the pattern is annotated or has a rest element,
+/// whose value depends on every property listed next to it.
+///
+/// A property qualifies when it binds a plain name under a plain key, with
+/// no default: `{ a }`, `{ key: a }`, `{ "key": a }` or `{ 0: a }`.
+pub fn Properties(Pattern:&ObjectPat) -> Vec<Property<'_>> {
+	if Pattern.type_ann.is_some()
+		|| Pattern.props.iter().any(|Prop| matches!(Prop, ObjectPatProp::Rest(_)))
+	{
+		return Vec::new();
+	}
+
+	Pattern
+		.props
+		.iter()
+		.enumerate()
+		.filter_map(|(Index, Prop)| {
+			let (Name, Member) = match Prop {
+				ObjectPatProp::Assign(AssignPatProp { key, value: None, .. }) => {
+					(&key.id, MemberProp::Ident(IdentName::new(key.id.sym.clone(), key.id.span)))
+				},
+				ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+					let Pat::Ident(BindingIdent { id, type_ann: None }) = &**value else {
+						return None;
+					};
+
+					let Member = match key {
+						PropName::Ident(Key) => MemberProp::Ident(Key.clone()),
+						PropName::Str(Key) => Computed(Lit::Str(Key.clone())),
+						PropName::Num(Key) => Computed(Lit::Num(Key.clone())),
+						PropName::Computed(_) | PropName::BigInt(_) => return None,
+					};
+
+					(id, Member)
+				},
+				_ => return None,
+			};
+
+			Some(Property { Index, Name, Member })
+		})
+		.collect()
+}
+
+/// Whether every property of the pattern moves, so its declarator goes.
+pub fn Emptied(Pattern:&ObjectPat, Planned:&HashSet<Id>) -> bool {
+	let Plain = Properties(Pattern);
+
+	!Plain.is_empty()
+		&& Plain.len() == Pattern.props.len()
+		&& Plain.iter().all(|Property| Planned.contains(&Property.Name.to_id()))
+}
+
+/// The member access `Source.key` read in place of a destructured name.
+pub fn Member(Source:&Expr, Member:MemberProp, Span:Span) -> Expr {
+	Expr::Member(MemberExpr { span:Span, obj:Box::new(Source.clone()), prop:Member })
+}
+
+/// The text [`Member`] puts after the source, as `.a`, `["a-b"]` or `[0]`.
+pub fn Text(Member:&MemberProp) -> String {
+	match Member {
+		MemberProp::Ident(IdentName { sym, .. }) => format!(".{}", sym),
+		MemberProp::Computed(ComputedPropName { expr, .. }) => {
+			let Key = match &**expr {
+				Expr::Lit(Lit::Str(Str { raw: Some(Raw), .. }))
+				| Expr::Lit(Lit::Num(Number { raw: Some(Raw), .. })) => Raw.to_string(),
+				Expr::Lit(Lit::Str(Str { value, .. })) => format!("{:?}", &**value),
+				Expr::Lit(Lit::Num(Number { value, .. })) => value.to_string(),
+				_ => String::new(),
+			};
+
+			format!("[{}]", Key)
+		},
+		MemberProp::PrivateName(_) => String::new(),
+	}
+}
+
+/// A computed member for a literal key.
+fn Computed(Key:Lit) -> MemberProp {
+	MemberProp::Computed(ComputedPropName { span:DUMMY_SP, expr:Box::new(Expr::Lit(Key)) })
+}
+
+use std::collections::HashSet;
+
+use swc_common::{DUMMY_SP, Span};
+use swc_ecma_ast::*;
@@ -0,0 +1,219 @@
+/// A function scope, or the top level, together with what decides whether its
+/// bindings can be reached by name at runtime.
+#[derive(Default)]
+struct Scope {
+	/// Bindings declared directly in the scope.
+	Declared:Vec<Id>,
+	/// Whether a construct inside the scope can reach its bindings by name.
+	Tainted:bool,
+	/// Whether the scope is an arrow function, which has no `arguments` of
+	/// its own.
+	Arrow:bool,
+	/// Whether `arguments` of the scope aliases its parameters: sloppy-mode
+	/// functions with only plain parameters.
+	Aliased:bool,
+}
+
+/// Pre-scan that finds the scopes whose bindings can be reached by name at
+/// runtime, where the static counts of the Analyzer mean nothing.
+///
+/// A direct `eval(...)` and a `with` statement can name any binding visible
+/// where they run, so they taint their own scope and every enclosing one.
+/// Reading `arguments` in a sloppy-mode function with plain parameters taints
+/// that function only, since writes through `arguments[i]` change its
+/// parameters and the other way round.
+#[derive(Default)]
+pub struct Guard {
+	/// Scopes enclosing the node being visited, innermost last.
+	Scope:Vec<Scope>,
+	/// Whether the node being visited is strict-mode code.
+	Strict:bool,
+	/// Bindings declared in a tainted scope, which are never inlined.
+	pub Dynamic:HashSet<Id>,
+	/// Every construct that tainted a scope, with its span.
+	pub Bailout:Vec<(Reason, Span)>,
+}
+
+impl Guard {
+	/// Scans the given module or script.
+	pub fn Scan(Program:&Program) -> Self {
+		let Strict = match Program {
+			Program::Module(_) => true,
+			Program::Script(Script { body, .. }) => Directive(body),
+		};
+
+		let mut Guard = Self { Strict, ..Self::default() };
+
+		Guard.Enter(false, true, |This| Program.visit_children_with(This));
+
+		Guard
+	}
+
+	/// Runs `Inner` inside a new scope, then sets its bindings aside when it
+	/// ended up tainted.
+	fn Enter(&mut self, Arrow:bool, Simple:bool, Inner:impl FnOnce(&mut Self)) {
+		self.Scope.push(Scope { Arrow, Aliased:!self.Strict && Simple, ..Scope::default() });
+
+		Inner(self);
+
+		if let Some(Scope) = self.Scope.pop()
+			&& Scope.Tainted
+		{
+			self.Dynamic.extend(Scope.Declared);
+		}
+	}
+
+	/// Runs `Inner` for a function body, which is strict-mode code when it
+	/// starts with a `"use strict"` directive. `Simple` tells whether every
+	/// parameter is a plain identifier.
+	fn Function(&mut self, Arrow:bool, Simple:bool, Body:&[Stmt], Inner:impl FnOnce(&mut Self)) {
+		let Strict = self.Strict;
+
+		self.Strict |= Directive(Body);
+
+		self.Enter(Arrow, Simple, Inner);
+
+		self.Strict = Strict;
+	}
+
+	/// Records a binding of the innermost scope.
+	fn Declare(&mut self, Ident:&Ident) {
+		if let Some(Scope) = self.Scope.last_mut() {
+			Scope.Declared.push(Ident.to_id());
+		}
+	}
+
+	/// Taints every scope enclosing the construct.
+	fn Taint(&mut self, Reason:Reason, Span:Span) {
+		for Scope in &mut self.Scope {
+			Scope.Tainted = true;
+		}
+
+		self.Bailout.push((Reason, Span));
+	}
+}
+
+/// Whether a statement list starts with a `"use strict"` directive.
+fn Directive(Body:&[Stmt]) -> bool {
+	Body.iter()
+		.map_while(|Stmt| {
+			match Stmt {
+				Stmt::Expr(ExprStmt { expr, .. }) => {
+					match &**expr {
+						Expr::Lit(Lit::Str(Str { value, .. })) => Some(&**value == "use strict"),
+						_ => None,
+					}
+				},
+				_ => None,
+			}
+		})
+		.any(|Strict| Strict)
+}
+
+impl Visit for Guard {
+	fn visit_binding_ident(&mut self, Binding:&BindingIdent) {
+		self.Declare(&Binding.id);
+
+		Binding.visit_children_with(self);
+	}
+
+	fn visit_fn_decl(&mut self, Fn:&FnDecl) {
+		self.Declare(&Fn.ident);
+
+		Fn.function.visit_with(self);
+	}
+
+	fn visit_class_decl(&mut self, Class:&ClassDecl) {
+		self.Declare(&Class.ident);
+
+		Class.class.visit_with(self);
+	}
+
+	/// Class bodies are always strict-mode code.
+	fn visit_class(&mut self, Class:&Class) {
+		let Strict = self.Strict;
+
+		self.Strict = true;
+
+		Class.visit_children_with(self);
+
+		self.Strict = Strict;
+	}
+
+	fn visit_function(&mut self, Function:&Function) {
+		let Simple = Function.params.iter().all(|Param| matches!(Param.pat, Pat::Ident(_)));
+
+		let Body = Function.body.as_ref().map(|Body| &Body.stmts[..]).unwrap_or_default();
+
+		self.Function(false, Simple, Body, |This| Function.visit_children_with(This));
+	}
+
+	fn visit_arrow_expr(&mut self, Arrow:&ArrowExpr) {
+		let Body:&[Stmt] = match &*Arrow.body {
+			BlockStmtOrExpr::BlockStmt(Body) => &Body.stmts[..],
+			BlockStmtOrExpr::Expr(_) => &[],
+		};
+
+		self.Function(true, false, Body, |This| Arrow.visit_children_with(This));
+	}
+
+	fn visit_constructor(&mut self, Constructor:&Constructor) {
+		self.Enter(false, false, |This| Constructor.visit_children_with(This));
+	}
+
+	fn visit_getter_prop(&mut self, Getter:&GetterProp) {
+		let Body = Getter.body.as_ref().map(|Body| &Body.stmts[..]).unwrap_or_default();
+
+		self.Function(false, true, Body, |This| Getter.visit_children_with(This));
+	}
+
+	fn visit_setter_prop(&mut self, Setter:&SetterProp) {
+		let Body = Setter.body.as_ref().map(|Body| &Body.stmts[..]).unwrap_or_default();
+
+		let Simple = matches!(*Setter.param, Pat::Ident(_));
+
+		self.Function(false, Simple, Body, |This| Setter.visit_children_with(This));
+	}
+
+	/// A direct `eval(...)`.
+	fn visit_call_expr(&mut self, Call:&CallExpr) {
+		if let Callee::Expr(Callee) = &Call.callee
+			&& matches!(&**Callee, Expr::Ident(Ident) if &*Ident.sym == "eval")
+		{
+			self.Taint(Reason::Eval, Call.span);
+		}
+
+		Call.visit_children_with(self);
+	}
+
+	fn visit_with_stmt(&mut self, With:&WithStmt) {
+		self.Taint(Reason::With, With.span);
+
+		With.visit_children_with(self);
+	}
+
+	/// `arguments` read in a function whose parameters it aliases.
+	fn visit_ident(&mut self, Ident:&Ident) {
+		if &*Ident.sym != "arguments" || self.Scope.len() < 2 {
+			return;
+		}
+
+		let Function = self.Scope[1..].iter_mut().rev().find(|Scope| !Scope.Arrow);
+
+		if let Some(Scope) = Function
+			&& Scope.Aliased && !Scope.Tainted
+		{
+			Scope.Tainted = true;
+
+			self.Bailout.push((Reason::Arguments, Ident.span));
+		}
+	}
+}
+
+use std::collections::HashSet;
+
+use swc_common::Span;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::Struct::Eliminate::Diagnostic::Reason;
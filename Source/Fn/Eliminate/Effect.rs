@@ -0,0 +1,268 @@
+/// Global values that can be read without side effects.
+const VALUE:[&str; 3] = ["undefined", "NaN", "Infinity"];
+
+/// Global functions whose calls have no side effects when their arguments are
+/// pure.
+const FUNCTION:[&str; 8] =
+	["Boolean", "Number", "String", "Symbol", "isFinite", "isNaN", "parseFloat", "parseInt"];
+
+/// Global namespaces whose members are pure to read and pure to call with pure
+/// arguments.
+const NAMESPACE:[&str; 1] = ["Math"];
+
+/// Decides whether evaluating an expression or statement can have observable
+/// side effects.
+#[derive(Clone, Copy)]
+pub struct Purity<'a> {
+	/// Comments of the file, used to honour `/*#__PURE__*/` annotations.
+	pub Comments:Option<&'a dyn Comments>,
+	/// Context the resolver gives to identifiers that refer to no
+	/// declaration.
+	pub Unresolved:SyntaxContext,
+}
+
+impl<'a> Purity<'a> {
+	/// Whether the identifier refers to the given global rather than to a
+	/// local binding.
+	fn Global(&self, Ident:&Ident, Names:&[&str]) -> bool {
+		Ident.ctxt == self.Unresolved && Names.contains(&&*Ident.sym)
+	}
+
+	/// Whether the expression is a member of one of the known-pure global
+	/// namespaces, such as `Math.max`.
+	fn Namespace(&self, Expr:&Expr) -> bool {
+		match Expr {
+			Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(_), .. }) => {
+				matches!(&**obj, Expr::Ident(Ident) if self.Global(Ident, &NAMESPACE))
+			},
+			_ => false,
+		}
+	}
+
+	/// Whether every argument is pure and none is spread.
+	fn Arguments(&self, Args:&[ExprOrSpread]) -> bool {
+		Args.iter().all(|Arg| Arg.spread.is_none() && self.Expr(&Arg.expr))
+	}
+
+	/// Whether a property name can be computed without side effects.
+	fn Key(&self, Key:&PropName) -> bool {
+		match Key {
+			PropName::Computed(ComputedPropName { expr, .. }) => self.Expr(expr),
+			_ => true,
+		}
+	}
+
+	/// Whether defining the class runs no user code.
+	fn Class(&self, Class:&Class) -> bool {
+		Class.decorators.is_empty()
+			&& Class.super_class.as_deref().is_none_or(|Super| self.Expr(Super))
+			&& Class.body.iter().all(|Member| {
+				match Member {
+					ClassMember::Method(ClassMethod { key, function, .. }) => {
+						function.decorators.is_empty() && self.Key(key)
+					},
+					ClassMember::ClassProp(ClassProp { key, value, is_static, decorators, .. }) => {
+						decorators.is_empty()
+							&& self.Key(key)
+							&& (!is_static || value.as_deref().is_none_or(|Value| self.Expr(Value)))
+					},
+					ClassMember::PrivateProp(PrivateProp { value, is_static, decorators, .. }) => {
+						decorators.is_empty()
+							&& (!is_static || value.as_deref().is_none_or(|Value| self.Expr(Value)))
+					},
+					ClassMember::Constructor(_)
+					| ClassMember::PrivateMethod(_)
+					| ClassMember::TsIndexSignature(_)
+					| ClassMember::Empty(_) => true,
+					_ => false,
+				}
+			})
+	}
+
+	/// Whether evaluating the expression can have no observable side effects.
+	///
+	/// Literals, closures, local reads, pure operators over pure operands,
+	/// calls annotated with `/*#__PURE__*/` and calls to known-pure globals are
+	/// pure. Everything else, including property reads that may hit getters,
+	/// is treated as impure.
+	pub fn Expr(&self, Expr:&Expr) -> bool {
+		match Expr {
+			Expr::Lit(_) | Expr::This(_) | Expr::MetaProp(_) => true,
+			Expr::Arrow(_) | Expr::Fn(_) => true,
+			Expr::Class(ClassExpr { class, .. }) => self.Class(class),
+			Expr::Ident(Ident) => Ident.ctxt != self.Unresolved || self.Global(Ident, &VALUE),
+			Expr::Paren(ParenExpr { expr, .. })
+			| Expr::TsAs(TsAsExpr { expr, .. })
+			| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| Expr::TsNonNull(TsNonNullExpr { expr, .. })
+			| Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+			| Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+			| Expr::TsInstantiation(TsInstantiation { expr, .. }) => self.Expr(expr),
+			Expr::Unary(UnaryExpr { op: UnaryOp::TypeOf, arg, .. }) => {
+				matches!(&**arg, Expr::Ident(_)) || self.Expr(arg)
+			},
+			Expr::Unary(UnaryExpr { op, arg, .. }) => *op != UnaryOp::Delete && self.Expr(arg),
+			Expr::Bin(BinExpr { op, left, right, .. }) => {
+				!matches!(op, BinaryOp::In | BinaryOp::InstanceOf)
+					&& self.Expr(left) && self.Expr(right)
+			},
+			Expr::Cond(CondExpr { test, cons, alt, .. }) => {
+				self.Expr(test) && self.Expr(cons) && self.Expr(alt)
+			},
+			Expr::Seq(SeqExpr { exprs, .. }) => exprs.iter().all(|Expr| self.Expr(Expr)),
+			Expr::Tpl(Tpl { exprs, .. }) => exprs.iter().all(|Expr| self.Expr(Expr)),
+			Expr::Array(ArrayLit { elems, .. }) => {
+				elems.iter().flatten().all(|Elem| Elem.spread.is_none() && self.Expr(&Elem.expr))
+			},
+			Expr::Object(ObjectLit { props, .. }) => {
+				props.iter().all(|Prop| {
+					match Prop {
+						PropOrSpread::Spread(_) => false,
+						PropOrSpread::Prop(Prop) => {
+							match &**Prop {
+								Prop::Shorthand(_) => true,
+								Prop::KeyValue(KeyValueProp { key, value }) => {
+									self.Key(key) && self.Expr(value)
+								},
+								Prop::Getter(GetterProp { key, .. })
+								| Prop::Setter(SetterProp { key, .. })
+								| Prop::Method(MethodProp { key, .. }) => self.Key(key),
+								Prop::Assign(_) => false,
+							}
+						},
+					}
+				})
+			},
+			Expr::Member(_) => self.Namespace(Expr),
+			Expr::Call(CallExpr { span, callee: Callee::Expr(Callee), args, .. }) => {
+				(self.Comments.is_some_and(|Comments| Comments.has_flag(span.lo, "PURE"))
+					|| matches!(&**Callee, Expr::Ident(Ident) if self.Global(Ident, &FUNCTION))
+					|| self.Namespace(Callee))
+					&& self.Arguments(args)
+			},
+			Expr::New(NewExpr { span, args, .. }) => {
+				self.Comments.is_some_and(|Comments| Comments.has_flag(span.lo, "PURE"))
+					&& args.as_deref().is_none_or(|Args| self.Arguments(Args))
+			},
+			_ => false,
+		}
+	}
+
+	/// Whether executing the statement can have no observable side effects.
+	pub fn Stmt(&self, Stmt:&Stmt) -> bool {
+		match Stmt {
+			Stmt::Empty(_) => true,
+			Stmt::Expr(ExprStmt { expr, .. }) => self.Expr(expr),
+			Stmt::Decl(Decl) => self.Decl(Decl),
+			_ => false,
+		}
+	}
+
+	/// Whether evaluating the declaration can have no observable side
+	/// effects.
+	pub fn Decl(&self, Decl:&Decl) -> bool {
+		match Decl {
+			Decl::Fn(_) | Decl::TsInterface(_) | Decl::TsTypeAlias(_) => true,
+			Decl::Class(ClassDecl { class, declare, .. }) => *declare || self.Class(class),
+			Decl::Var(VarDecl) => {
+				VarDecl.declare
+					|| VarDecl.decls.iter().all(|Decl| {
+						matches!(Decl.name, Pat::Ident(_))
+							&& Decl.init.as_deref().is_none_or(|Init| self.Expr(Init))
+					})
+			},
+			Decl::TsEnum(TsEnumDecl) => TsEnumDecl.declare,
+			Decl::TsModule(TsModuleDecl) => TsModuleDecl.declare,
+			Decl::Using(_) => false,
+		}
+	}
+
+	/// Whether executing the module item can have no observable side effects.
+	pub fn ModuleItem(&self, Item:&ModuleItem) -> bool {
+		match Item {
+			ModuleItem::Stmt(Stmt) => self.Stmt(Stmt),
+			ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+				self.Decl(decl)
+			},
+			ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+				expr, ..
+			})) => self.Expr(expr),
+			ModuleItem::ModuleDecl(
+				ModuleDecl::Import(_)
+				| ModuleDecl::ExportNamed(_)
+				| ModuleDecl::ExportAll(_)
+				| ModuleDecl::ExportDefaultDecl(_),
+			) => true,
+			ModuleItem::ModuleDecl(_) => false,
+		}
+	}
+
+	/// Whether calling the expression where a plain identifier was called
+	/// passes something the identifier did not: a member access passes its
+	/// object as `this`, and the global `eval` turns into a direct `eval`
+	/// that sees the bindings in scope.
+	pub fn Receiver(&self, Expr:&Expr) -> bool {
+		match Expr {
+			Expr::Paren(ParenExpr { expr, .. })
+			| Expr::TsAs(TsAsExpr { expr, .. })
+			| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| Expr::TsNonNull(TsNonNullExpr { expr, .. })
+			| Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+			| Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+			| Expr::TsInstantiation(TsInstantiation { expr, .. }) => self.Receiver(expr),
+			Expr::Member(_) | Expr::SuperProp(_) => true,
+			Expr::Ident(Ident) => self.Global(Ident, &["eval"]),
+			Expr::OptChain(OptChainExpr { base, .. }) => matches!(&**base, OptChainBase::Member(_)),
+			_ => false,
+		}
+	}
+
+	/// Whether any side effect inside `Node` is evaluated before `Position`.
+	///
+	/// Evaluation order is approximated by source order: every expression
+	/// that ends before `Position` runs before it, and expressions enclosing
+	/// `Position` are searched further. Reading a plain identifier, such as
+	/// the callee in `use(r)`, is not counted.
+	pub fn Before<T:VisitWith<Order<'a>>>(&self, Node:&T, Position:BytePos) -> bool {
+		let mut Order = Order { Purity:*self, Position, Found:false };
+
+		Node.visit_with(&mut Order);
+
+		Order.Found
+	}
+}
+
+/// Whether the expression is a primitive literal, which is equally cheap and
+/// equally identical wherever and however often it is evaluated.
+pub fn Literal(Expr:&Expr) -> bool {
+	matches!(
+		Expr,
+		Expr::Lit(Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::Null(_) | Lit::BigInt(_))
+	)
+}
+
+/// Visitor behind [`Purity::Before`].
+pub struct Order<'a> {
+	/// The purity rules to apply.
+	Purity:Purity<'a>,
+	/// The position the search stops at.
+	Position:BytePos,
+	/// Set once an impure expression is found before `Position`.
+	Found:bool,
+}
+
+impl<'a> Visit for Order<'a> {
+	fn visit_expr(&mut self, Expr:&Expr) {
+		let Span = Expr.span();
+
+		if Span.hi <= self.Position {
+			self.Found |= !matches!(Expr, Expr::Ident(_)) && !self.Purity.Expr(Expr);
+		} else if Span.lo <= self.Position {
+			Expr.visit_children_with(self);
+		}
+	}
+}
+
+use swc_common::{BytePos, Spanned, SyntaxContext, comments::Comments};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
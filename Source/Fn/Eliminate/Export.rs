@@ -0,0 +1,187 @@
+/// Pre-scan that collects every local binding a module exports, in any export
+/// form and wherever it appears, before any rewriting starts. CommonJS exports
+/// through `module.exports` and `exports` count as well.
+#[derive(Default)]
+pub struct Exporter {
+	/// The exported local bindings.
+	pub Exported:HashSet<Id>,
+}
+
+impl Exporter {
+	/// Collects the exported local bindings of the given program.
+	pub fn Collect(Program:&Program) -> HashSet<Id> {
+		let mut Exporter = Self::default();
+
+		Program.visit_with(&mut Exporter);
+
+		Exporter.Exported
+	}
+
+	/// Records the bindings introduced by an exported declaration.
+	fn Decl(&mut self, Decl:&Decl) {
+		match Decl {
+			Decl::Var(VarDecl) => {
+				for Declarator in &VarDecl.decls {
+					self.Exported.extend(Bindings(&Declarator.name).into_iter().map(Ident::to_id));
+				}
+			},
+			Decl::Using(UsingDecl) => {
+				for Declarator in &UsingDecl.decls {
+					self.Exported.extend(Bindings(&Declarator.name).into_iter().map(Ident::to_id));
+				}
+			},
+			Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+				self.Exported.insert(ident.to_id());
+			},
+			Decl::TsEnum(TsEnumDecl) => {
+				self.Exported.insert(TsEnumDecl.id.to_id());
+			},
+			Decl::TsModule(TsModuleDecl) => {
+				if let TsModuleName::Ident(Ident) = &TsModuleDecl.id {
+					self.Exported.insert(Ident.to_id());
+				}
+			},
+			Decl::TsInterface(_) | Decl::TsTypeAlias(_) => {},
+		}
+	}
+
+	/// Records an exported expression when it is a plain reference, as in
+	/// `export default x` or `export = x`.
+	fn Expr(&mut self, Expr:&Expr) {
+		match Expr {
+			Expr::Ident(Ident) => {
+				self.Exported.insert(Ident.to_id());
+			},
+			Expr::Paren(ParenExpr { expr, .. })
+			| Expr::TsAs(TsAsExpr { expr, .. })
+			| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| Expr::TsNonNull(TsNonNullExpr { expr, .. })
+			| Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+			| Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => self.Expr(expr),
+			_ => {},
+		}
+	}
+
+	/// Records a value assigned to a CommonJS export: a plain reference, or
+	/// the shorthand and plain-reference properties of an object literal, as
+	/// in `module.exports = { x, y: z }`.
+	fn Common(&mut self, Value:&Expr) {
+		match Value {
+			Expr::Object(ObjectLit { props, .. }) => {
+				for Prop in props {
+					match Prop {
+						PropOrSpread::Prop(Prop) => {
+							match &**Prop {
+								Prop::Shorthand(Ident) => {
+									self.Exported.insert(Ident.to_id());
+								},
+								Prop::KeyValue(KeyValueProp { value, .. }) => self.Expr(value),
+								_ => {},
+							}
+						},
+						PropOrSpread::Spread(SpreadElement { expr, .. }) => self.Expr(expr),
+					}
+				}
+			},
+			Expr::Paren(ParenExpr { expr, .. }) => self.Common(expr),
+			_ => self.Expr(Value),
+		}
+	}
+}
+
+/// Whether the member expression is `module.exports`.
+fn Module(Member:&MemberExpr) -> bool {
+	matches!(&Member.prop, MemberProp::Ident(IdentName { sym, .. }) if &**sym == "exports")
+		&& matches!(&*Member.obj, Expr::Ident(Ident) if &*Ident.sym == "module")
+}
+
+/// Whether the member expression is a CommonJS export target:
+/// `module.exports`, or a property of `exports` or `module.exports`.
+fn Common(Member:&MemberExpr) -> bool {
+	Module(Member)
+		|| match &*Member.obj {
+			Expr::Ident(Ident) => &*Ident.sym == "exports",
+			Expr::Member(Object) => Module(Object),
+			_ => false,
+		}
+}
+
+impl Visit for Exporter {
+	/// `module.exports = x`, `module.exports = { x }`, `exports.x = x` and
+	/// `module.exports.x = x`.
+	fn visit_assign_expr(&mut self, Assign:&AssignExpr) {
+		if let AssignTarget::Simple(SimpleAssignTarget::Member(Member)) = &Assign.left
+			&& Assign.op == AssignOp::Assign && Common(Member)
+		{
+			self.Common(&Assign.right);
+		}
+
+		Assign.visit_children_with(self);
+	}
+
+	/// `export const x = ...`, `export function f() {}`, `export declare ...`
+	/// and the same forms inside namespaces.
+	fn visit_export_decl(&mut self, Export:&ExportDecl) {
+		self.Decl(&Export.decl);
+
+		Export.visit_children_with(self);
+	}
+
+	/// `export default function f() {}` and `export default class C {}`.
+	fn visit_export_default_decl(&mut self, Export:&ExportDefaultDecl) {
+		match &Export.decl {
+			DefaultDecl::Fn(FnExpr { ident: Some(Ident), .. })
+			| DefaultDecl::Class(ClassExpr { ident: Some(Ident), .. }) => {
+				self.Exported.insert(Ident.to_id());
+			},
+			_ => {},
+		}
+
+		Export.visit_children_with(self);
+	}
+
+	/// `export default x`.
+	fn visit_export_default_expr(&mut self, Export:&ExportDefaultExpr) {
+		self.Expr(&Export.expr);
+
+		Export.visit_children_with(self);
+	}
+
+	/// `export { x }` and `export { x as y }`, wherever they appear. Re-exports
+	/// with a `from` clause name bindings of another module and are skipped.
+	fn visit_named_export(&mut self, Export:&NamedExport) {
+		if Export.src.is_some() {
+			return;
+		}
+
+		for Specifier in &Export.specifiers {
+			if let ExportSpecifier::Named(ExportNamedSpecifier {
+				orig: ModuleExportName::Ident(Orig), ..
+			}) = Specifier
+			{
+				self.Exported.insert(Orig.to_id());
+			}
+		}
+	}
+
+	/// `export = x`.
+	fn visit_ts_export_assignment(&mut self, Export:&TsExportAssignment) {
+		self.Expr(&Export.expr);
+
+		Export.visit_children_with(self);
+	}
+
+	/// `export import A = B.C`.
+	fn visit_ts_import_equals_decl(&mut self, Import:&TsImportEqualsDecl) {
+		if Import.is_export {
+			self.Exported.insert(Import.id.to_id());
+		}
+	}
+}
+
+use std::collections::HashSet;
+
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use super::Analysis::Bindings;
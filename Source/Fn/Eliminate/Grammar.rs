@@ -0,0 +1,72 @@
+/// Picks the syntax a file is parsed with, from `Dialect` or, when it is
+/// `Auto`, from the file extension.
+///
+/// `.ts`, `.mts` and `.cts` parse as TypeScript and `.tsx` as TypeScript
+/// with JSX. `.js`, `.mjs`, `.cjs` and `.jsx` parse as JavaScript with JSX,
+/// the way bundlers read them. `.mts` and `.cts` reject the `<T>x` assertion,
+/// declaration files such as `.d.ts` parse as ambient code, and decorators are
+/// on for every dialect. Any other extension parses as TypeScript.
+pub fn Select(Path:&Path, Dialect:Dialect) -> Syntax {
+	let Name = Path.file_name().and_then(|Name| Name.to_str()).unwrap_or_default();
+
+	let Extension = Path.extension().and_then(|Extension| Extension.to_str()).unwrap_or_default();
+
+	let Dialect = match Dialect {
+		Dialect::Auto => {
+			match Extension {
+				"tsx" => Dialect::Tsx,
+				"js" | "mjs" | "cjs" => Dialect::Javascript,
+				"jsx" => Dialect::Jsx,
+				_ => Dialect::Typescript,
+			}
+		},
+		Dialect => Dialect,
+	};
+
+	match Dialect {
+		Dialect::Javascript | Dialect::Jsx => {
+			Syntax::Es(EsSyntax { jsx:true, decorators:true, ..Default::default() })
+		},
+		Dialect::Tsx => Syntax::Typescript(TsSyntax { tsx:true, decorators:true, ..Default::default() }),
+		Dialect::Auto | Dialect::Typescript => {
+			Syntax::Typescript(TsSyntax {
+				decorators:true,
+				dts:[".d.ts", ".d.mts", ".d.cts"].iter().any(|Suffix| Name.ends_with(Suffix)),
+				disallow_ambiguous_jsx_like:matches!(Extension, "mts" | "cts"),
+				..Default::default()
+			})
+		},
+	}
+}
+
+/// Parses the file as a module or as a script.
+///
+/// `.mjs` and `.mts` files are always modules. Any other file is a module
+/// when it uses `import` or `export`, and a script otherwise, so sloppy-mode
+/// code and CommonJS files parse too.
+///
+/// The errors the parser recovers from, such as a legacy octal literal in a
+/// module, fail the parse like any other.
+pub fn Parse(Parser:&mut Parser<Lexer>, Path:&Path) -> io::Result<Program> {
+	let Program = match Path.extension().and_then(|Extension| Extension.to_str()) {
+		Some("mjs" | "mts") => Parser.parse_module().map(Program::Module),
+		_ => Parser.parse_program(),
+	};
+
+	let mut Errors = Parser.take_errors();
+
+	match Program {
+		Ok(Program) if Errors.is_empty() => return Ok(Program),
+		Ok(_) => {},
+		Err(Error) => Errors.push(Error),
+	}
+
+	Err(io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", Errors)))
+}
+
+use std::{io, path::Path};
+
+use swc_ecma_ast::Program;
+use swc_ecma_parser::{EsSyntax, Parser, Syntax, TsSyntax, lexer::Lexer};
+
+use crate::Struct::Eliminate::Option::Dialect;
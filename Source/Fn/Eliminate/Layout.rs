@@ -0,0 +1,91 @@
+/// The byte order mark some editors put at the start of UTF-8 files.
+const BOM:char = '\u{feff}';
+
+/// Spaces the indentation unit is sliced from, so it stays `'static` for the
+/// printer.
+const SPACE:&str = "        ";
+
+/// How a file writes line breaks, whether it starts with a byte order mark and
+/// how it indents, so the output can be written the same way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Style {
+	/// The line break, `"\n"` or `"\r\n"`.
+	pub Newline:&'static str,
+	/// Whether the file starts with a byte order mark.
+	pub Bom:bool,
+	/// One level of indentation, a tab or a run of spaces.
+	pub Indent:&'static str,
+}
+
+impl Default for Style {
+	fn default() -> Self { Self { Newline:"\n", Bom:false, Indent:&SPACE[..4] } }
+}
+
+impl Style {
+	/// Detects the style of the file and strips its byte order mark, which
+	/// the parser must not see.
+	///
+	/// The line break is the one most lines end with. The indentation is a
+	/// tab when most indented lines start with one, otherwise the most common
+	/// step between the space indentation of consecutive lines.
+	pub fn Detect(Code:&mut String) -> Self {
+		let Bom = Code.starts_with(BOM);
+
+		if Bom {
+			Code.drain(..BOM.len_utf8());
+		}
+
+		let Feed = Code.matches('\n').count();
+
+		let Return = Code.matches("\r\n").count();
+
+		let Newline = if Return * 2 > Feed { "\r\n" } else { "\n" };
+
+		let mut Tab = 0;
+
+		let mut Space = 0;
+
+		let mut Step = [0usize; 9];
+
+		let mut Previous = 0;
+
+		for Line in Code.lines().filter(|Line| !Line.trim().is_empty()) {
+			if Line.starts_with('\t') {
+				Tab += 1;
+
+				continue;
+			}
+
+			let Width = Line.len() - Line.trim_start_matches(' ').len();
+
+			if Width > 0 {
+				Space += 1;
+			}
+
+			let Difference = Width.abs_diff(Previous);
+
+			if (1..Step.len()).contains(&Difference) {
+				Step[Difference] += 1;
+			}
+
+			Previous = Width;
+		}
+
+		let Indent = if Tab > Space {
+			"\t"
+		} else {
+			(1..Step.len())
+				.rev()
+				.max_by_key(|Width| Step[*Width])
+				.filter(|Width| Step[*Width] > 0)
+				.map_or(Self::default().Indent, |Width| &SPACE[..Width])
+		};
+
+		Self { Newline, Bom, Indent }
+	}
+
+	/// Writes the style's byte order mark back in front of the output.
+	pub fn Restore(&self, Code:String) -> String {
+		if self.Bom { format!("{}{}", BOM, Code) } else { Code }
+	}
+}
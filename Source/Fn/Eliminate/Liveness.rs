@@ -0,0 +1,307 @@
+/// Finds the stores to local bindings that no read can observe, from the
+/// same use/def facts the Planner works with.
+///
+/// A plain assignment `x = ...` is dead when nothing reads `x` at all, or
+/// when every read of `x` comes before it and none can run again after it:
+/// no read sits in a nested function, which may be called later, and the
+/// assignment sits in no loop of its own function, whose next iteration may
+/// read it. The pure initializer of a `let` or `var` is dead when nothing
+/// reads the binding but something assigns it.
+///
+/// Bindings that are exported, reached by name at runtime, referred to from
+/// a type or declared more than once are left alone, and so are constants,
+/// classes, functions and imports, which throw or are hoisted when assigned.
+pub struct Scanner<'a> {
+	/// Decides which initializers can go without their side effects.
+	Purity:Purity<'a>,
+	/// Usage facts for every binding.
+	Binding:HashMap<Id, Binding>,
+	/// Bindings that are exported.
+	Exported:HashSet<Id>,
+	/// Bindings that can be reached by name at runtime.
+	Dynamic:HashSet<Id>,
+	/// Whether the program is a script, whose top-level bindings other
+	/// scripts can reach as globals.
+	Script:bool,
+	/// Bindings that must not be assigned: constants and classes.
+	Constant:HashSet<Id>,
+	/// Targets of the dead assignments and names of the declarators whose
+	/// initializer is dead.
+	Dead:Vec<Ident>,
+}
+
+impl<'a> Scanner<'a> {
+	/// Scans the module or script, returning the target of every dead
+	/// assignment and the name of every declarator whose initializer is
+	/// dead, in source order.
+	pub fn Scan(Program:&Program, Purity:Purity<'a>) -> Vec<Ident> {
+		let Analyzer = Analyzer::Analyze(Program);
+
+		let mut Scanner = Self {
+			Purity,
+			Binding:Analyzer.Binding,
+			Exported:Analyzer.Exported,
+			Dynamic:Analyzer.Dynamic,
+			Script:matches!(Program, Program::Script(_)),
+			Constant:HashSet::new(),
+			Dead:Vec::new(),
+		};
+
+		Program.visit_with(&mut Scanner);
+
+		for (Name, Binding) in &Scanner.Binding {
+			if !Scanner.Local(Name, Binding) {
+				continue;
+			}
+
+			Scanner.Dead.extend(
+				Binding
+					.Assigned
+					.iter()
+					.filter(|Store| Store.Span.lo > Binding.Span.lo && Overwritten(Binding, Store))
+					.map(|Store| Ident::new(Name.0.clone(), Store.Span, Name.1)),
+			);
+		}
+
+		Scanner.Dead.sort_by_key(|Ident| Ident.span.lo);
+
+		Scanner.Dead
+	}
+
+	/// Whether assigning the binding can only matter to its own reads: it is
+	/// declared once by a variable or a parameter, not exported, out of
+	/// reach of `eval`, `with` and `arguments`, never referred to from a
+	/// type, and not a global of a script.
+	fn Local(&self, Name:&Id, Binding:&Binding) -> bool {
+		!self.Exported.contains(Name)
+			&& !self.Dynamic.contains(Name)
+			&& !self.Constant.contains(Name)
+			&& !Binding.Hoisted
+			&& Binding.Declaration == 1
+			&& Binding.Typed == 0
+			&& (!self.Script || Binding.Nested())
+	}
+}
+
+impl<'a> Visit for Scanner<'a> {
+	fn visit_var_decl(&mut self, VarDecl:&VarDecl) {
+		VarDecl.visit_children_with(self);
+
+		if VarDecl.kind == VarDeclKind::Const {
+			let Names = VarDecl.decls.iter().flat_map(|Decl| Bindings(&Decl.name));
+
+			self.Constant.extend(Names.map(Ident::to_id));
+
+			return;
+		}
+
+		if VarDecl.declare {
+			return;
+		}
+
+		for Decl in &VarDecl.decls {
+			let (Pat::Ident(BindingIdent { id, .. }), Some(Init)) = (&Decl.name, Decl.init.as_deref())
+			else {
+				continue;
+			};
+
+			let Unread = self.Binding.get(&id.to_id()).is_some_and(|Binding| {
+				Binding.Read == 0 && Binding.Write > 0 && self.Local(&id.to_id(), Binding)
+			});
+
+			if Unread && self.Purity.Expr(Init) {
+				self.Dead.push(id.clone());
+			}
+		}
+	}
+
+	fn visit_using_decl(&mut self, Using:&UsingDecl) {
+		Using.visit_children_with(self);
+
+		let Names = Using.decls.iter().flat_map(|Decl| Bindings(&Decl.name));
+
+		self.Constant.extend(Names.map(Ident::to_id));
+	}
+
+	fn visit_class_decl(&mut self, Class:&ClassDecl) {
+		Class.visit_children_with(self);
+
+		self.Constant.insert(Class.ident.to_id());
+	}
+}
+
+/// Whether the plain assignment `Store` to a local binding is overwritten or
+/// dropped before anything reads it.
+fn Overwritten(Binding:&Binding, Store:&Reference) -> bool {
+	if Binding.Read == 0 {
+		return true;
+	}
+
+	// Code inside a nested function may run at any later time.
+	let Later = |(_, Boundary):&(usize, Boundary)| {
+		matches!(Boundary, Boundary::Function | Boundary::Hoisted)
+	};
+
+	let Repeated = Store
+		.Context
+		.iter()
+		.rev()
+		.take_while(|Boundary| !Later(Boundary))
+		.any(|(_, Boundary)| *Boundary == Boundary::Loop);
+
+	let Now = |Reference:&Reference| {
+		Binding.Crossed(Reference).is_some_and(|Crossed| !Crossed.iter().any(Later))
+	};
+
+	!Repeated && Now(Store) && Binding.Position.iter().all(|Read| Read.Order < Store.Order && Now(Read))
+}
+
+/// Rewrite that removes the dead stores the [`Scanner`] found: a dead
+/// assignment becomes its right-hand side, or goes with its statement when
+/// that side is pure, and a dead initializer goes with its `=`.
+pub struct Sweeper<'a> {
+	/// Decides which right-hand sides can go without their side effects.
+	Purity:Purity<'a>,
+	/// Spans of the dead targets and declarator names.
+	Dead:HashSet<Span>,
+	/// The stores removed.
+	pub Swept:Vec<Ident>,
+	/// Text edits reproducing the rewrite on the original source.
+	pub Edits:Vec<Edit>,
+}
+
+impl<'a> Sweeper<'a> {
+	/// Creates a sweeper for the given dead stores.
+	pub fn New(Purity:Purity<'a>, Dead:&[Ident]) -> Self {
+		Self {
+			Purity,
+			Dead:Dead.iter().map(|Ident| Ident.span).filter(|Span| !Span.is_dummy()).collect(),
+			Swept:Vec::new(),
+			Edits:Vec::new(),
+		}
+	}
+
+	/// The target of the assignment, when it is a dead store.
+	fn Store<'b>(&self, Assign:&'b AssignExpr) -> std::option::Option<&'b Ident> {
+		match &Assign.left {
+			AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent { id, .. }))
+				if Assign.op == AssignOp::Assign && self.Dead.contains(&id.span) =>
+			{
+				Some(id)
+			},
+			_ => None,
+		}
+	}
+
+	/// Drops the statements of a list that only make a dead store of a pure
+	/// value, moving their comments to the next remaining entry, then
+	/// rewrites the others.
+	fn Sweep<T:Item + VisitMutWith<Self>>(&mut self, Items:&mut Vec<T>) {
+		let mut Gone = Vec::new();
+
+		for (Index, Item) in Items.iter().enumerate() {
+			let Some(Expr::Assign(Assign)) = Item.Evaluated() else {
+				continue;
+			};
+
+			if let Some(Ident) = self.Store(Assign).filter(|_| self.Purity.Expr(&Assign.right)) {
+				self.Swept.push(Ident.clone());
+
+				self.Edits.push(Edit::Remove(Item.span()));
+
+				Gone.push(Index);
+			}
+		}
+
+		if let Some(Comments) = self.Purity.Comments {
+			for Index in &Gone {
+				let Taken = Detach(Comments, Items[*Index].span());
+
+				let Next = (*Index + 1..Items.len()).find(|Index| !Gone.contains(Index));
+
+				let Previous = (0..*Index).rev().find(|Index| !Gone.contains(Index));
+
+				match (Next, Previous) {
+					(Some(Next), _) => Lead(Comments, Items[Next].span().lo, Taken),
+					(None, Some(Previous)) => Trail(Comments, Items[Previous].span().hi, Taken),
+					(None, None) => {},
+				}
+			}
+		}
+
+		Prune(Items, |Index| Gone.contains(&Index));
+
+		for Item in Items.iter_mut() {
+			Item.visit_mut_with(self);
+		}
+	}
+}
+
+impl<'a> VisitMut for Sweeper<'a> {
+	/// Replaces a dead assignment with its right-hand side.
+	fn visit_mut_expr(&mut self, Expr:&mut Expr) {
+		Expr.visit_mut_children_with(self);
+
+		let Expr::Assign(Assign) = Expr else {
+			return;
+		};
+
+		let Some(Ident) = self.Store(Assign).cloned() else {
+			return;
+		};
+
+		self.Swept.push(Ident);
+
+		self.Edits.push(Edit::Replace {
+			Span:Assign.span,
+			Init:Text(&Assign.right),
+			Key:None,
+			Member:None,
+			Type:None,
+		});
+
+		let Right = std::mem::replace(&mut *Assign.right, Expr::Invalid(Invalid { span:DUMMY_SP }));
+
+		*Expr = Wrap(Right);
+	}
+
+	/// Removes a dead initializer together with its `=`.
+	fn visit_mut_var_declarator(&mut self, Decl:&mut VarDeclarator) {
+		let Pat::Ident(BindingIdent { id, type_ann }) = &Decl.name else {
+			return Decl.visit_mut_children_with(self);
+		};
+
+		if !self.Dead.contains(&id.span) || Decl.init.is_none() {
+			return Decl.visit_mut_children_with(self);
+		}
+
+		let End = type_ann.as_ref().map_or(id.span.hi, |Annotation| Annotation.span.hi);
+
+		self.Swept.push(id.clone());
+
+		self.Edits.push(Edit::Remove(Span::new(End, Decl.span.hi)));
+
+		Decl.init = None;
+	}
+
+	fn visit_mut_module_items(&mut self, Items:&mut Vec<ModuleItem>) { self.Sweep(Items); }
+
+	fn visit_mut_stmts(&mut self, Stmts:&mut Vec<Stmt>) { self.Sweep(Stmts); }
+}
+
+use std::collections::{HashMap, HashSet};
+
+use swc_common::{DUMMY_SP, Span};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use super::{
+	Analysis::{Analyzer, Binding, Bindings, Boundary, Reference},
+	Prune,
+	Comment::{Detach, Lead, Trail},
+	Effect::Purity,
+	Paren::Wrap,
+	Reduce::Text,
+	Statement::Item,
+	Text::Edit,
+};
@@ -0,0 +1,489 @@
+/// Comma expressions.
+const SEQ:u8 = 0;
+
+/// Assignments, arrow functions and `yield`.
+const ASSIGN:u8 = 1;
+
+/// Conditional expressions.
+const COND:u8 = 2;
+
+/// Relational operators, `as`, `satisfies` and `as const`.
+const RELATIONAL:u8 = 10;
+
+/// Exponentiation.
+const EXP:u8 = 14;
+
+/// Unary operators, `await` and `<T>` assertions.
+const UNARY:u8 = 15;
+
+/// Update expressions.
+const UPDATE:u8 = 16;
+
+/// Calls, member accesses, `new`, optional chains and tagged templates.
+const LHS:u8 = 17;
+
+/// Identifiers, literals and anything else that never needs parentheses.
+const PRIMARY:u8 = 18;
+
+/// Position an expression takes within its parent, deciding how loosely it may
+/// bind without parentheses.
+#[derive(Clone, Copy)]
+pub enum Slot {
+	/// Anywhere a comma expression is allowed, such as statements and
+	/// computed keys.
+	Any,
+	/// Arguments, elements, initializers and assignment right-hand sides.
+	Assign,
+	/// The test of a conditional expression.
+	Test,
+	/// The left operand of a binary operator.
+	Left(BinaryOp),
+	/// The right operand of a binary operator.
+	Right(BinaryOp),
+	/// The operand of a unary operator or `await`.
+	Unary(Option<UnaryOp>),
+	/// The operand of `as`, `satisfies` or `as const`.
+	Relational,
+	/// The object of a member access, the tag of a template, or the operand
+	/// of `!`.
+	Object,
+	/// The callee of a call.
+	Callee,
+	/// The callee of `new`.
+	New,
+}
+
+/// Binding strength of an expression when printed, from the loosest comma
+/// expression to primary expressions.
+pub fn Precedence(Expr:&Expr) -> u8 {
+	match Expr {
+		Expr::Seq(_) => SEQ,
+		Expr::Yield(_) | Expr::Arrow(_) | Expr::Assign(_) => ASSIGN,
+		Expr::Cond(_) => COND,
+		Expr::Bin(BinExpr { op, .. }) => Operator(*op),
+		Expr::TsAs(_) | Expr::TsSatisfies(_) | Expr::TsConstAssertion(_) => RELATIONAL,
+		Expr::Unary(_) | Expr::Await(_) | Expr::TsTypeAssertion(_) => UNARY,
+		Expr::Update(_) => UPDATE,
+		Expr::Call(_)
+		| Expr::New(_)
+		| Expr::Member(_)
+		| Expr::SuperProp(_)
+		| Expr::OptChain(_)
+		| Expr::TaggedTpl(_)
+		| Expr::TsNonNull(_)
+		| Expr::TsInstantiation(_) => LHS,
+		_ => PRIMARY,
+	}
+}
+
+/// Binding strength of a binary operator.
+fn Operator(Op:BinaryOp) -> u8 {
+	match Op {
+		BinaryOp::NullishCoalescing => 3,
+		BinaryOp::LogicalOr => 4,
+		BinaryOp::LogicalAnd => 5,
+		BinaryOp::BitOr => 6,
+		BinaryOp::BitXor => 7,
+		BinaryOp::BitAnd => 8,
+		BinaryOp::EqEq | BinaryOp::NotEq | BinaryOp::EqEqEq | BinaryOp::NotEqEq => 9,
+		BinaryOp::Lt
+		| BinaryOp::LtEq
+		| BinaryOp::Gt
+		| BinaryOp::GtEq
+		| BinaryOp::In
+		| BinaryOp::InstanceOf => RELATIONAL,
+		BinaryOp::LShift | BinaryOp::RShift | BinaryOp::ZeroFillRShift => 11,
+		BinaryOp::Add | BinaryOp::Sub => 12,
+		BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => 13,
+		BinaryOp::Exp => EXP,
+	}
+}
+
+/// Whether `??` would be mixed with `||` or `&&` without parentheses, which is
+/// a syntax error.
+fn Mixed(Op:BinaryOp, Child:&Expr) -> bool {
+	let Expr::Bin(BinExpr { op: Inner, .. }) = Child else {
+		return false;
+	};
+
+	match Op {
+		BinaryOp::NullishCoalescing => matches!(Inner, BinaryOp::LogicalOr | BinaryOp::LogicalAnd),
+		BinaryOp::LogicalOr | BinaryOp::LogicalAnd => *Inner == BinaryOp::NullishCoalescing,
+		_ => false,
+	}
+}
+
+/// Whether the expression starts with the given sign, so that placing it
+/// after the same sign would print `--` or `++`.
+fn Signed(Child:&Expr, Sign:UnaryOp) -> bool {
+	match Child {
+		Expr::Unary(UnaryExpr { op, .. }) => *op == Sign,
+		Expr::Update(UpdateExpr { op, prefix: true, .. }) => {
+			matches!(
+				(op, Sign),
+				(UpdateOp::MinusMinus, UnaryOp::Minus) | (UpdateOp::PlusPlus, UnaryOp::Plus)
+			)
+		},
+		_ => false,
+	}
+}
+
+/// Whether a call appears along the callee chain of the expression, which
+/// would otherwise become the target of an enclosing `new`.
+fn Called(Child:&Expr) -> bool {
+	match Child {
+		Expr::Call(_) => true,
+		Expr::Member(MemberExpr { obj, .. }) => Called(obj),
+		Expr::TaggedTpl(TaggedTpl { tag, .. }) => Called(tag),
+		Expr::TsNonNull(TsNonNullExpr { expr, .. }) => Called(expr),
+		_ => false,
+	}
+}
+
+/// Whether `Child` needs parentheses to keep its meaning in `Slot`.
+pub fn Need(Slot:Slot, Child:&Expr) -> bool {
+	let Level = Precedence(Child);
+
+	match Slot {
+		Slot::Any => false,
+		Slot::Assign => Level < ASSIGN,
+		Slot::Test => Level <= COND,
+		Slot::Left(BinaryOp::Exp) => Level < UPDATE,
+		Slot::Right(BinaryOp::Exp) => Level < EXP,
+		Slot::Left(Op) => Level < Operator(Op) || Mixed(Op, Child),
+		Slot::Right(Op) => {
+			Level <= Operator(Op)
+				|| Mixed(Op, Child)
+				|| (Op == BinaryOp::Sub && Signed(Child, UnaryOp::Minus))
+				|| (Op == BinaryOp::Add && Signed(Child, UnaryOp::Plus))
+		},
+		Slot::Unary(Op) => {
+			Level < UNARY
+				|| Op.is_some_and(|Op| {
+					matches!(Op, UnaryOp::Minus | UnaryOp::Plus) && Signed(Child, Op)
+				})
+		},
+		Slot::Relational => Level < RELATIONAL,
+		Slot::Object => {
+			Level < LHS
+				|| matches!(Child, Expr::OptChain(_) | Expr::Lit(Lit::Num(_)))
+				|| matches!(Child, Expr::New(NewExpr { args: None, .. }))
+		},
+		Slot::Callee => {
+			Level < LHS
+				|| matches!(Child, Expr::OptChain(_) | Expr::Fn(_) | Expr::Class(_))
+				|| matches!(Child, Expr::New(NewExpr { args: None, .. }))
+		},
+		Slot::New => Level < LHS || matches!(Child, Expr::OptChain(_)) || Called(Child),
+	}
+}
+
+/// Wraps an inlined initializer in a marker parenthesis, which
+/// [`Parenthesizer`] later keeps or removes depending on its new parent.
+/// Function and class expressions are wrapped too, to read clearly when
+/// called.
+pub fn Wrap(Init:Expr) -> Expr {
+	if Precedence(&Init) == PRIMARY
+		&& !matches!(Init, Expr::Lit(Lit::Num(_)) | Expr::Fn(_) | Expr::Class(_))
+	{
+		return Init;
+	}
+
+	Expr::Paren(ParenExpr { span:DUMMY_SP, expr:Box::new(Init) })
+}
+
+/// Removes the marker parenthesis around `Expr` when `Slot` does not need
+/// it.
+fn Fix(Expr:&mut Expr, Slot:Slot) {
+	let Inner = match Expr {
+		Expr::Paren(ParenExpr { span, expr }) if span.is_dummy() && !Need(Slot, expr) => {
+			std::mem::replace(&mut **expr, Expr::Invalid(Invalid { span:DUMMY_SP }))
+		},
+		_ => return,
+	};
+
+	*Expr = Inner;
+}
+
+/// The expression printed first within `Expr`.
+fn Leftmost(Expr:&mut Expr) -> &mut Expr {
+	// Decided through a shared borrow, so the mutable one below is only taken
+	// by the arm that descends.
+	if matches!(&*Expr, Expr::Seq(SeqExpr { exprs, .. }) if exprs.is_empty()) {
+		return Expr;
+	}
+
+	match Expr {
+		Expr::Bin(BinExpr { left, .. }) => Leftmost(left),
+		Expr::Seq(SeqExpr { exprs, .. }) => Leftmost(&mut exprs[0]),
+		Expr::Cond(CondExpr { test, .. }) => Leftmost(test),
+		Expr::Call(CallExpr { callee: Callee::Expr(Callee), .. }) => Leftmost(Callee),
+		Expr::Member(MemberExpr { obj, .. }) => Leftmost(obj),
+		Expr::TaggedTpl(TaggedTpl { tag, .. }) => Leftmost(tag),
+		Expr::Update(UpdateExpr { prefix: false, arg, .. }) => Leftmost(arg),
+		Expr::TsAs(TsAsExpr { expr, .. })
+		| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+		| Expr::TsNonNull(TsNonNullExpr { expr, .. })
+		| Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => Leftmost(expr),
+		Expr::OptChain(OptChainExpr { base, .. }) => {
+			match &mut **base {
+				OptChainBase::Member(MemberExpr { obj, .. }) => Leftmost(obj),
+				OptChainBase::Call(OptCall { callee, .. }) => Leftmost(callee),
+			}
+		},
+		_ => Expr,
+	}
+}
+
+/// Parenthesizes the expression printed first within `Expr` when it would
+/// otherwise be read as the start of a block or a declaration.
+fn Lead(Expr:&mut Expr, Declaration:bool) {
+	let Leftmost = Leftmost(Expr);
+
+	if matches!(Leftmost, Expr::Object(_)) || (Declaration && matches!(Leftmost, Expr::Fn(_) | Expr::Class(_)))
+	{
+		let Inner = std::mem::replace(Leftmost, Expr::Invalid(Invalid { span:DUMMY_SP }));
+
+		*Leftmost = Expr::Paren(ParenExpr { span:DUMMY_SP, expr:Box::new(Inner) });
+	}
+}
+
+/// Pass that runs after inlining and keeps the marker parentheses added by
+/// [`Wrap`] only where the precedence and associativity of the new parent
+/// require them.
+pub struct Parenthesizer;
+
+impl VisitMut for Parenthesizer {
+	fn visit_mut_expr(&mut self, Expr:&mut Expr) {
+		Expr.visit_mut_children_with(self);
+
+		match Expr {
+			Expr::Paren(ParenExpr { expr, .. }) => Fix(expr, Slot::Any),
+			Expr::Seq(SeqExpr { exprs, .. }) => {
+				for Expr in exprs {
+					Fix(Expr, Slot::Any);
+				}
+			},
+			Expr::Assign(AssignExpr { right, .. }) => Fix(right, Slot::Assign),
+			Expr::Yield(YieldExpr { arg: Some(arg), .. }) => Fix(arg, Slot::Assign),
+			Expr::Arrow(ArrowExpr { body, .. }) => {
+				if let BlockStmtOrExpr::Expr(Body) = &mut **body {
+					Fix(Body, Slot::Assign);
+
+					Lead(Body, false);
+				}
+			},
+			Expr::Cond(CondExpr { test, cons, alt, .. }) => {
+				Fix(test, Slot::Test);
+
+				Fix(cons, Slot::Assign);
+
+				Fix(alt, Slot::Assign);
+			},
+			Expr::Bin(BinExpr { op, left, right, .. }) => {
+				Fix(left, Slot::Left(*op));
+
+				Fix(right, Slot::Right(*op));
+			},
+			Expr::Unary(UnaryExpr { op, arg, .. }) => Fix(arg, Slot::Unary(Some(*op))),
+			Expr::Await(AwaitExpr { arg, .. }) => Fix(arg, Slot::Unary(None)),
+			Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => Fix(expr, Slot::Unary(None)),
+			Expr::TsAs(TsAsExpr { expr, .. })
+			| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| Expr::TsConstAssertion(TsConstAssertion { expr, .. }) => Fix(expr, Slot::Relational),
+			Expr::TsNonNull(TsNonNullExpr { expr, .. })
+			| Expr::TsInstantiation(TsInstantiation { expr, .. }) => Fix(expr, Slot::Object),
+			Expr::Member(MemberExpr { obj, prop, .. }) => {
+				Fix(obj, Slot::Object);
+
+				if let MemberProp::Computed(ComputedPropName { expr, .. }) = prop {
+					Fix(expr, Slot::Any);
+				}
+			},
+			Expr::TaggedTpl(TaggedTpl { tag, .. }) => Fix(tag, Slot::Object),
+			Expr::Call(CallExpr { callee: Callee::Expr(Callee), .. }) => Fix(Callee, Slot::Callee),
+			Expr::New(NewExpr { callee, .. }) => Fix(callee, Slot::New),
+			Expr::OptChain(OptChainExpr { base, .. }) => {
+				match &mut **base {
+					OptChainBase::Member(MemberExpr { obj, .. }) => Fix(obj, Slot::Object),
+					OptChainBase::Call(OptCall { callee, .. }) => Fix(callee, Slot::Callee),
+				}
+			},
+			Expr::Tpl(Tpl { exprs, .. }) => {
+				for Expr in exprs {
+					Fix(Expr, Slot::Any);
+				}
+			},
+			_ => {},
+		}
+	}
+
+	/// Arguments, array elements and spreads.
+	fn visit_mut_expr_or_spread(&mut self, Arg:&mut ExprOrSpread) {
+		Arg.visit_mut_children_with(self);
+
+		Fix(&mut Arg.expr, Slot::Assign);
+	}
+
+	fn visit_mut_key_value_prop(&mut self, Prop:&mut KeyValueProp) {
+		Prop.visit_mut_children_with(self);
+
+		Fix(&mut Prop.value, Slot::Assign);
+	}
+
+	fn visit_mut_computed_prop_name(&mut self, Computed:&mut ComputedPropName) {
+		Computed.visit_mut_children_with(self);
+
+		Fix(&mut Computed.expr, Slot::Assign);
+	}
+
+	fn visit_mut_var_declarator(&mut self, Var:&mut VarDeclarator) {
+		Var.visit_mut_children_with(self);
+
+		if let Some(Init) = &mut Var.init {
+			Fix(Init, Slot::Assign);
+		}
+	}
+
+	fn visit_mut_assign_pat(&mut self, Assign:&mut AssignPat) {
+		Assign.visit_mut_children_with(self);
+
+		Fix(&mut Assign.right, Slot::Assign);
+	}
+
+	fn visit_mut_assign_pat_prop(&mut self, Assign:&mut AssignPatProp) {
+		Assign.visit_mut_children_with(self);
+
+		if let Some(Value) = &mut Assign.value {
+			Fix(Value, Slot::Assign);
+		}
+	}
+
+	fn visit_mut_class_prop(&mut self, Prop:&mut ClassProp) {
+		Prop.visit_mut_children_with(self);
+
+		if let Some(Value) = &mut Prop.value {
+			Fix(Value, Slot::Assign);
+		}
+	}
+
+	fn visit_mut_private_prop(&mut self, Prop:&mut PrivateProp) {
+		Prop.visit_mut_children_with(self);
+
+		if let Some(Value) = &mut Prop.value {
+			Fix(Value, Slot::Assign);
+		}
+	}
+
+	fn visit_mut_class(&mut self, Class:&mut Class) {
+		Class.visit_mut_children_with(self);
+
+		if let Some(Super) = &mut Class.super_class {
+			Fix(Super, Slot::Object);
+		}
+	}
+
+	/// An expression statement must not start with `{`, `function` or
+	/// `class`.
+	fn visit_mut_expr_stmt(&mut self, Stmt:&mut ExprStmt) {
+		Stmt.visit_mut_children_with(self);
+
+		Fix(&mut Stmt.expr, Slot::Any);
+
+		Lead(&mut Stmt.expr, true);
+	}
+
+	/// `export default` must not be followed by `function` or `class` that
+	/// is only part of the expression.
+	fn visit_mut_export_default_expr(&mut self, Export:&mut ExportDefaultExpr) {
+		Export.visit_mut_children_with(self);
+
+		Fix(&mut Export.expr, Slot::Assign);
+
+		if !matches!(&*Export.expr, Expr::Fn(_) | Expr::Class(_)) {
+			Lead(&mut Export.expr, true);
+		}
+	}
+
+	fn visit_mut_return_stmt(&mut self, Return:&mut ReturnStmt) {
+		Return.visit_mut_children_with(self);
+
+		if let Some(Arg) = &mut Return.arg {
+			Fix(Arg, Slot::Any);
+		}
+	}
+
+	fn visit_mut_throw_stmt(&mut self, Throw:&mut ThrowStmt) {
+		Throw.visit_mut_children_with(self);
+
+		Fix(&mut Throw.arg, Slot::Any);
+	}
+
+	fn visit_mut_if_stmt(&mut self, If:&mut IfStmt) {
+		If.visit_mut_children_with(self);
+
+		Fix(&mut If.test, Slot::Any);
+	}
+
+	fn visit_mut_while_stmt(&mut self, While:&mut WhileStmt) {
+		While.visit_mut_children_with(self);
+
+		Fix(&mut While.test, Slot::Any);
+	}
+
+	fn visit_mut_do_while_stmt(&mut self, While:&mut DoWhileStmt) {
+		While.visit_mut_children_with(self);
+
+		Fix(&mut While.test, Slot::Any);
+	}
+
+	fn visit_mut_switch_stmt(&mut self, Switch:&mut SwitchStmt) {
+		Switch.visit_mut_children_with(self);
+
+		Fix(&mut Switch.discriminant, Slot::Any);
+	}
+
+	/// An `in` operator directly in the head of a `for` would read as a
+	/// `for-in` loop.
+	fn visit_mut_for_stmt(&mut self, For:&mut ForStmt) {
+		For.visit_mut_children_with(self);
+
+		if let Some(VarDeclOrExpr::Expr(Init)) = &mut For.init
+			&& !matches!(Init.unwrap_parens(), Expr::Bin(BinExpr { op: BinaryOp::In, .. }))
+		{
+			Fix(Init, Slot::Any);
+		}
+
+		if let Some(Test) = &mut For.test {
+			Fix(Test, Slot::Any);
+		}
+
+		if let Some(Update) = &mut For.update {
+			Fix(Update, Slot::Any);
+		}
+	}
+
+	fn visit_mut_for_of_stmt(&mut self, For:&mut ForOfStmt) {
+		For.visit_mut_children_with(self);
+
+		Fix(&mut For.right, Slot::Assign);
+	}
+
+	fn visit_mut_for_in_stmt(&mut self, For:&mut ForInStmt) {
+		For.visit_mut_children_with(self);
+
+		Fix(&mut For.right, Slot::Any);
+	}
+
+	fn visit_mut_jsx_expr_container(&mut self, Container:&mut JSXExprContainer) {
+		Container.visit_mut_children_with(self);
+
+		if let JSXExpr::Expr(Expr) = &mut Container.expr {
+			Fix(Expr, Slot::Any);
+		}
+	}
+}
+
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{VisitMut, VisitMutWith};
@@ -0,0 +1,668 @@
+/// Where the single read of a binding ends up once the initializers planned so
+/// far have moved to their uses.
+#[derive(Clone, Debug)]
+struct Site {
+	/// Positions of the read, outermost first: the original position of the
+	/// identifier that stays in its statement list, followed by the position
+	/// inside each initializer that moves along with the read.
+	Path:Vec<BytePos>,
+	/// Constructs enclosing the read after the moves.
+	Context:Context,
+	/// Whether side effects of an enclosing moved initializer run before the
+	/// read.
+	Blocked:bool,
+	/// Whether the read is called or tags a template once the moves are
+	/// done.
+	Bound:bool,
+}
+
+/// Decides, in a single run over the use/def graph, which declarators the
+/// Inliner removes, and which of them are never used at all.
+///
+/// The module or script is analyzed once. Every statement list then feeds its
+/// declarators to a worklist that is drained from the end, so a declaration
+/// is always decided after every declaration that may consume it. Planning a
+/// move updates the sites of the reads inside the moved initializer, instead
+/// of re-analyzing the module, so chains such as `a → b → ... → z` resolve in
+/// one run.
+pub struct Planner<'a> {
+	/// The options controlling how far inlining may go.
+	Option:&'a Option,
+	/// Decides which initializers can be moved without reordering side
+	/// effects.
+	Purity:Purity<'a>,
+	/// Usage facts for every binding.
+	Binding:HashMap<Id, Binding>,
+	/// Bindings that are exported and must not be inlined.
+	Exported:HashSet<Id>,
+	/// Bindings that can be reached by name at runtime and must not be
+	/// inlined.
+	Dynamic:HashSet<Id>,
+	/// Sources of destructuring declarations some of whose properties move,
+	/// when they are read again at the new sites or by the properties left,
+	/// and bindings read by the initializer of a removed unused declaration,
+	/// which must not move into it.
+	Pinned:HashSet<Id>,
+	/// Updated sites of reads that moved along with a planned initializer.
+	Site:HashMap<Id, Site>,
+	/// The single read of every binding read once, by position.
+	Reads:BTreeMap<BytePos, Id>,
+	/// Paths of the sites impure initializers move to.
+	Landed:Vec<Vec<BytePos>>,
+	/// The bindings whose declarators are removed.
+	Planned:HashSet<Id>,
+	/// The bindings whose declarations are removed because nothing uses
+	/// them.
+	Dead:HashSet<Id>,
+	/// Whether the program is a script, whose top-level bindings other
+	/// scripts can reach as globals.
+	Script:bool,
+}
+
+impl<'a> Planner<'a> {
+	/// Analyzes the module or script and plans which declarators to remove:
+	/// those whose initializer moves to their single use, and those nothing
+	/// uses, as the `Dead` option asks.
+	pub fn Plan(
+		Program:&Program,
+		Option:&'a Option,
+		Purity:Purity<'a>,
+	) -> (HashSet<Id>, HashSet<Id>) {
+		let Analyzer = Analyzer::Analyze(Program);
+
+		let Reads = Analyzer
+			.Binding
+			.iter()
+			.filter(|(_, Binding)| Binding.Read == 1)
+			.filter_map(|(Name, Binding)| {
+				Binding.Position.first().map(|Reference| (Reference.Span.lo, Name.clone()))
+			})
+			.collect();
+
+		let mut Planner = Self {
+			Option,
+			Purity,
+			Binding:Analyzer.Binding,
+			Exported:Analyzer.Exported,
+			Dynamic:Analyzer.Dynamic,
+			Pinned:HashSet::new(),
+			Site:HashMap::new(),
+			Reads,
+			Landed:Vec::new(),
+			Planned:HashSet::new(),
+			Dead:HashSet::new(),
+			Script:matches!(Program, Program::Script(_)),
+		};
+
+		Program.visit_with(&mut Planner);
+
+		(Planner.Planned, Planner.Dead)
+	}
+
+	/// Whether the analysis proved the binding is read exactly once, declared
+	/// once, not exported and out of reach of `eval`, `with` and
+	/// `arguments`.
+	///
+	/// A binding with any write after its initialization, including one
+	/// inside a closure or a `for` head, is never inlined or deleted, and
+	/// neither is one a type refers to.
+	///
+	/// A read naming a JSX element, as in `<C />`, takes nothing but an
+	/// identifier, so a binding read that way stays. So does one at the top
+	/// level of a script, which another script may use as a global.
+	fn Inlinable(&self, Name:&Id) -> bool {
+		!self.Exported.contains(Name)
+			&& !self.Dynamic.contains(Name)
+			&& !self.Pinned.contains(Name)
+			&& self.Binding.get(Name).is_some_and(|Binding| {
+				Binding.Read == 1
+					&& Binding.Write == 0
+					&& Binding.Declaration == 1
+					&& Binding.Tagged == 0
+					&& Binding.Typed == 0
+					&& (!self.Script || Binding.Nested())
+			})
+	}
+
+	/// Whether the `Dead` option removes the binding: it is never read,
+	/// written or referred to from a type, declared once, not exported and
+	/// out of reach of `eval`, `with` and `arguments`. At the top level of a
+	/// script, where another script may use it as a global, it stays.
+	fn Unused(&self, Name:&Id) -> bool {
+		self.Option.Dead != Dead::Keep
+			&& !self.Exported.contains(Name)
+			&& !self.Dynamic.contains(Name)
+			&& self.Binding.get(Name).is_some_and(|Binding| {
+				Binding.Read == 0
+					&& Binding.Write == 0
+					&& Binding.Declaration == 1
+					&& Binding.Typed == 0
+					&& (!self.Script || Binding.Nested())
+			})
+	}
+
+	/// Removes the unused `Name`, whose declaration spans `Span`. Bindings
+	/// read once inside it stay where they are for the rest of the run, so
+	/// nothing moves into code that goes away; they are removed in turn by a
+	/// later pass.
+	fn Discard(&mut self, Name:Id, Span:Span) {
+		let Inner = self.Reads.range(Span.lo..Span.hi).map(|(_, Name)| Name.clone());
+
+		self.Pinned.extend(Inner);
+
+		self.Dead.insert(Name);
+	}
+
+	/// The current site of the binding's single read.
+	fn Site(&self, Name:&Id) -> std::option::Option<Site> {
+		self.Site.get(Name).cloned().or_else(|| {
+			self.Binding.get(Name)?.Position.first().map(|Reference| {
+				Site {
+					Path:vec![Reference.Span.lo],
+					Context:Reference.Context.clone(),
+					Blocked:false,
+					Bound:Reference.Bound,
+				}
+			})
+		})
+	}
+
+	/// Whether an impure initializer moves into `Span` and runs before
+	/// `Path`. Initializers enclosing `Path` are covered by
+	/// [`Site::Blocked`] instead.
+	fn Landed(&self, Span:Span, Path:&[BytePos]) -> bool {
+		self.Landed.iter().any(|Landed| {
+			Within(Span, Landed[0]) && Landed.as_slice() < Path && !Path.starts_with(Landed)
+		})
+	}
+
+	/// Whether the node reads a binding before its declaration has run,
+	/// outside the functions it defines, which yields `undefined` for a
+	/// `var` and throws for `let`, `const` and `class`.
+	fn Early<T:for<'b> VisitWith<Early<'b>>>(&self, Node:&T) -> bool {
+		let mut Early = Early { Binding:&self.Binding, Found:false };
+
+		Node.visit_with(&mut Early);
+
+		Early.Found
+	}
+
+	/// Whether the initializer reads, outside the functions it defines, a
+	/// binding written anywhere, whose value may change before the new site
+	/// of the read.
+	fn Mutable(&self, Init:&Expr) -> bool {
+		let mut Mutable = Mutable { Binding:&self.Binding, Found:false };
+
+		Init.visit_with(&mut Mutable);
+
+		Mutable.Found
+	}
+
+	/// Whether moving `Init` away from `Name` changes the `name` it takes
+	/// from it, as anonymous functions, arrows and classes do. A function or
+	/// arrow still moves into the callee of a call or `new`, where nothing
+	/// else sees it. A class never does, since its static members can read
+	/// its name while it is defined.
+	fn Nameless(&self, Name:&Id, Init:&Expr) -> bool {
+		match Init {
+			Expr::Paren(ParenExpr { expr, .. })
+			| Expr::TsAs(TsAsExpr { expr, .. })
+			| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| Expr::TsNonNull(TsNonNullExpr { expr, .. })
+			| Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+			| Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => self.Nameless(Name, expr),
+			Expr::Class(ClassExpr { ident: None, .. }) => true,
+			Expr::Arrow(_) | Expr::Fn(FnExpr { ident: None, .. }) => {
+				!self
+					.Binding
+					.get(Name)
+					.and_then(|Binding| Binding.Position.first())
+					.is_some_and(|Reference| Reference.Callee)
+			},
+			_ => false,
+		}
+	}
+
+	/// Whether the plan removes the whole declarator, leaving nothing of it
+	/// behind.
+	fn Removed(&self, Decl:&VarDeclarator) -> bool {
+		match &Decl.name {
+			Pat::Ident(BindingIdent { id, .. }) => {
+				self.Planned.contains(&id.to_id())
+					|| (self.Dead.contains(&id.to_id())
+						&& Decl.init.as_deref().is_none_or(|Init| self.Purity.Expr(Init)))
+			},
+			Pat::Object(Pattern) => Emptied(Pattern, &self.Planned),
+			_ => false,
+		}
+	}
+
+	/// Whether executing the entry can have no observable side effects once
+	/// the planned declarators are gone and the planned initializers have
+	/// moved. An entry that may throw from a temporal dead zone has one.
+	fn Pure<T:Item + for<'b> VisitWith<Early<'b>>>(&self, Item:&T) -> bool {
+		let Clean = match Item.Var() {
+			Some(VarDecl) => {
+				VarDecl.declare
+					|| VarDecl.decls.iter().all(|Decl| {
+						self.Removed(Decl)
+							|| (matches!(Decl.name, Pat::Ident(_))
+								&& Decl.init.as_deref().is_none_or(|Init| self.Purity.Expr(Init)))
+					})
+			},
+			None => {
+				Item.Callable().and_then(Declared).is_some_and(|Ident| {
+					self.Planned.contains(&Ident.to_id()) || self.Dead.contains(&Ident.to_id())
+				}) || Item.Pure(&self.Purity)
+			},
+		};
+
+		Clean
+			&& !self.Landed.iter().any(|Landed| Within(Item.span(), Landed[0]))
+			&& !self.Early(Item)
+	}
+
+	/// Whether `Init`, bound to `Name` by declarator `Declarator` of
+	/// `Items[Index]`, can move to the current site of the single read of
+	/// `Name` without changing when, how often, or in which order side
+	/// effects happen.
+	///
+	/// The read must sit in a later item of the same list, so it runs after the
+	/// declaration: a `var` read after the block declaring it, or a `let` read
+	/// in a later `switch` case, may run without it. An initializer that reads
+	/// a binding before its declaration has run stays where it is, as does a
+	/// member access that would be called and take its object as `this`.
+	/// Nothing moves into a hoisted function declaration, which may be called
+	/// before the declaration runs. Initializers never move across a function
+	/// or loop boundary, except primitive literals when `Literal` is set, and
+	/// only pure ones move into code that may not run. Pure initializers can
+	/// otherwise always move, unless they read a binding that is written
+	/// somewhere. Those, and impure ones, only move when every item in between
+	/// is pure and nothing impure runs before the read inside its item, so no
+	/// write, direct or through a call, is crossed.
+	fn Movable<T:Item + VisitWith<Order<'a>> + for<'b> VisitWith<Early<'b>>>(
+		&self,
+		Items:&[T],
+		Index:usize,
+		Declarator:usize,
+		Name:&Id,
+		Init:&Expr,
+		Site:&Site,
+	) -> bool {
+		let Some(Binding) = self.Binding.get(Name) else {
+			return false;
+		};
+
+		let Position = Site.Path[0];
+
+		if !Items[Index + 1..].iter().any(|Item| Within(Item.span(), Position))
+			|| self.Early(Init)
+			|| (Site.Bound && self.Purity.Receiver(Init))
+		{
+			return false;
+		}
+
+		let Some(Crossed) = Site.Context.strip_prefix(&Binding.Context[..]) else {
+			return false;
+		};
+
+		let Pure = self.Purity.Expr(Init);
+
+		for (_, Boundary) in Crossed {
+			let Allowed = match Boundary {
+				Boundary::Hoisted => false,
+				Boundary::Function | Boundary::Loop => self.Option.Literal && Literal(Init),
+				Boundary::Branch => Pure,
+			};
+
+			if !Allowed {
+				return false;
+			}
+		}
+
+		if Pure && !self.Mutable(Init) {
+			return true;
+		}
+
+		if Site.Blocked {
+			return false;
+		}
+
+		let Some(VarDecl) = Items[Index].Var() else {
+			return self.Reach(Items, Index, Site);
+		};
+
+		// Later declarators of the same statement run before the read, and
+		// destructuring ones read properties.
+		if VarDecl.decls[Declarator + 1..].iter().any(|Decl| {
+			!self.Removed(Decl)
+				&& (!matches!(Decl.name, Pat::Ident(_))
+					|| Decl.init.as_deref().is_some_and(|Init| !self.Purity.Expr(Init)))
+		}) {
+			return false;
+		}
+
+		// So do later properties of the same pattern.
+		if let Pat::Object(Pattern) = &VarDecl.decls[Declarator].name {
+			let Plain = Properties(Pattern);
+
+			let Planned = |Index:usize| {
+				Plain.iter().any(|Property| {
+					Property.Index == Index && self.Planned.contains(&Property.Name.to_id())
+				})
+			};
+
+			let Own = Plain.iter().find(|Property| Property.Name.to_id() == *Name);
+
+			if Own.is_none_or(|Own| (Own.Index + 1..Pattern.props.len()).any(|Index| !Planned(Index)))
+			{
+				return false;
+			}
+		}
+
+		self.Reach(Items, Index, Site)
+	}
+
+	/// Whether nothing with side effects runs between `Items[Index]` and the
+	/// site of the read.
+	fn Reach<T:Item + VisitWith<Order<'a>> + for<'b> VisitWith<Early<'b>>>(
+		&self,
+		Items:&[T],
+		Index:usize,
+		Site:&Site,
+	) -> bool {
+		let Position = Site.Path[0];
+
+		for Item in &Items[Index + 1..] {
+			if Within(Item.span(), Position) {
+				return !self.Purity.Before(Item, Position) && !self.Landed(Item.span(), &Site.Path);
+			}
+
+			if !self.Pure(Item) {
+				return false;
+			}
+		}
+
+		false
+	}
+
+	/// Decides declarator `Declarator` of `Items[Index]`: a plain name
+	/// moves with its initializer, and every qualifying property of an
+	/// object pattern over an identifier or `this` moves as a member access
+	/// on that source, which then stays where it is for the rest of the run.
+	/// A function or class declaration moves as the equivalent named
+	/// expression.
+	///
+	/// A plain name nothing uses goes when its initializer is pure, or when
+	/// the `Dead` option keeps the initializer as a statement of its own and
+	/// nothing else is declared next to it. So does a function or class
+	/// declaration whose evaluation is pure.
+	fn Decide<T:Item + VisitWith<Order<'a>> + for<'b> VisitWith<Early<'b>>>(
+		&mut self,
+		Items:&[T],
+		Index:usize,
+		Declarator:usize,
+	) {
+		if let Some(Decl) = Items[Index].Callable() {
+			let Some(Ident) = Declared(Decl) else {
+				return;
+			};
+
+			if self.Unused(&Ident.to_id()) && self.Purity.Decl(Decl) {
+				self.Discard(Ident.to_id(), Decl.span());
+			} else if let Some(Init) = Expression(Decl).filter(|_| self.Option.Function) {
+				self.Settle(Items, Index, Declarator, Ident.to_id(), &Init);
+			}
+
+			return;
+		}
+
+		let Some(VarDecl) = Items[Index].Var() else {
+			return;
+		};
+
+		let Decl = &VarDecl.decls[Declarator];
+
+		if let Pat::Ident(BindingIdent { id, .. }) = &Decl.name
+			&& !VarDecl.declare && self.Unused(&id.to_id())
+		{
+			match Decl.init.as_deref() {
+				Some(Init) if !self.Purity.Expr(Init) => {
+					if self.Option.Dead == Dead::Expression && VarDecl.decls.len() == 1 {
+						self.Dead.insert(id.to_id());
+					}
+				},
+				_ => self.Discard(id.to_id(), Decl.span),
+			}
+
+			return;
+		}
+
+		let Some(Init) = Decl.init.as_deref() else {
+			return;
+		};
+
+		match &Decl.name {
+			Pat::Ident(BindingIdent { id, type_ann })
+				if (type_ann.is_none() || self.Option.Annotation != Annotation::Refuse)
+					&& !self.Nameless(&id.to_id(), Init) =>
+			{
+				self.Settle(Items, Index, Declarator, id.to_id(), Init);
+			},
+			Pat::Object(Pattern) if Source(Init) => {
+				// The source of a single property that moves is still read once,
+				// at its new site, and moves in turn.
+				let Single = Pattern.props.len() == 1;
+
+				for Property in Properties(Pattern).into_iter().rev() {
+					let Access = Member(Init, Property.Member, Init.span());
+
+					if self.Settle(Items, Index, Declarator, Property.Name.to_id(), &Access) && !Single
+						&& let Expr::Ident(Source) = Init
+					{
+						self.Pinned.insert(Source.to_id());
+					}
+				}
+			},
+			_ => {},
+		}
+	}
+
+	/// Decides whether `Name`, bound by declarator `Declarator` of
+	/// `Items[Index]` to `Init`, is removed and, when it is, moves the sites
+	/// of the reads inside `Init` to the site of its own read.
+	fn Settle<T:Item + VisitWith<Order<'a>> + for<'b> VisitWith<Early<'b>>>(
+		&mut self,
+		Items:&[T],
+		Index:usize,
+		Declarator:usize,
+		Name:Id,
+		Init:&Expr,
+	) -> bool {
+		if !self.Inlinable(&Name) {
+			return false;
+		}
+
+		let Some(Site) = self.Site(&Name) else {
+			return false;
+		};
+
+		if !self.Movable(Items, Index, Declarator, &Name, Init, &Site) {
+			return false;
+		}
+
+		let Declared = self.Binding.get(&Name).map(|Binding| Binding.Context.clone()).unwrap_or_default();
+
+		let Span = Init.span();
+
+		let Inner = self
+			.Reads
+			.range(Span.lo..Span.hi)
+			.map(|(Position, Name)| (*Position, Name.clone()))
+			.collect::<Vec<_>>();
+
+		for (Position, Read) in Inner {
+			let Some(Reference) = self.Binding.get(&Read).and_then(|Binding| Binding.Position.first())
+			else {
+				continue;
+			};
+
+			let mut Path = Site.Path.clone();
+
+			Path.push(Position);
+
+			let mut Context = Site.Context.clone();
+
+			Context.extend_from_slice(
+				Reference.Context.strip_prefix(&Declared[..]).unwrap_or(&Reference.Context[..]),
+			);
+
+			let Blocked = Site.Blocked || self.Purity.Before(Init, Position);
+
+			// A read that is all of the initializer is called when it is.
+			let Bound = Site.Bound
+				&& matches!(Init.unwrap_parens(), Expr::Ident(Ident) if Ident.span.lo == Position);
+
+			self.Site.insert(Read, Site { Path, Context, Blocked, Bound });
+		}
+
+		if !self.Purity.Expr(Init) {
+			self.Landed.push(Site.Path);
+		}
+
+		self.Planned.insert(Name);
+
+		true
+	}
+
+	/// Plans a statement list. Its declarators go on a worklist in source
+	/// order, which is drained from the end, each entry's nested statement
+	/// lists being planned just before its own declarators.
+	fn Drain<T>(&mut self, Items:&[T])
+	where
+		T: Item + VisitWith<Self> + VisitWith<Order<'a>> + for<'b> VisitWith<Early<'b>>, {
+		let mut Worklist = Vec::new();
+
+		for (Index, Item) in Items.iter().enumerate() {
+			if let Some(VarDecl) = Item.Var() {
+				Worklist.extend((0..VarDecl.decls.len()).map(|Declarator| (Index, Declarator)));
+			}
+
+			if (self.Option.Function || self.Option.Dead != Dead::Keep) && Item.Callable().is_some() {
+				Worklist.push((Index, 0));
+			}
+		}
+
+		for Index in (0..Items.len()).rev() {
+			Items[Index].visit_children_with(self);
+
+			while let Some(&(Entry, Declarator)) = Worklist.last() {
+				if Entry != Index {
+					break;
+				}
+
+				Worklist.pop();
+
+				self.Decide(Items, Index, Declarator);
+			}
+		}
+	}
+}
+
+impl<'a> Visit for Planner<'a> {
+	fn visit_module_items(&mut self, Items:&[ModuleItem]) { self.Drain(Items); }
+
+	fn visit_stmts(&mut self, Stmts:&[Stmt]) { self.Drain(Stmts); }
+}
+
+/// Visitor behind [`Planner::Early`].
+pub struct Early<'b> {
+	/// Usage facts for every binding.
+	Binding:&'b HashMap<Id, Binding>,
+	/// Set once a read before the declaration is found.
+	Found:bool,
+}
+
+impl<'b> Early<'b> {
+	/// Records a read of the given identifier.
+	fn Read(&mut self, Ident:&Ident) {
+		self.Found |= self.Binding.get(&Ident.to_id()).is_some_and(|Binding| {
+			Binding.Declaration > 0 && !Binding.Hoisted && Ident.span.lo < Binding.Span.lo
+		});
+	}
+}
+
+impl<'b> Visit for Early<'b> {
+	fn visit_expr(&mut self, Expr:&Expr) {
+		match Expr {
+			Expr::Ident(Ident) => self.Read(Ident),
+			_ => Expr.visit_children_with(self),
+		}
+	}
+
+	fn visit_prop(&mut self, Prop:&Prop) {
+		match Prop {
+			Prop::Shorthand(Ident) => self.Read(Ident),
+			_ => Prop.visit_children_with(self),
+		}
+	}
+
+	/// Function bodies run later, once the declaration may have run.
+	fn visit_function(&mut self, Function:&Function) { Function.decorators.visit_with(self); }
+
+	fn visit_arrow_expr(&mut self, _:&ArrowExpr) {}
+}
+
+/// Visitor behind [`Planner::Mutable`].
+pub struct Mutable<'b> {
+	/// Usage facts for every binding.
+	Binding:&'b HashMap<Id, Binding>,
+	/// Set once a read of a written binding is found.
+	Found:bool,
+}
+
+impl<'b> Mutable<'b> {
+	/// Records a read of the given identifier.
+	fn Read(&mut self, Ident:&Ident) {
+		self.Found |= self.Binding.get(&Ident.to_id()).is_some_and(|Binding| Binding.Write > 0);
+	}
+}
+
+impl<'b> Visit for Mutable<'b> {
+	fn visit_expr(&mut self, Expr:&Expr) {
+		match Expr {
+			Expr::Ident(Ident) => self.Read(Ident),
+			_ => Expr.visit_children_with(self),
+		}
+	}
+
+	fn visit_prop(&mut self, Prop:&Prop) {
+		match Prop {
+			Prop::Shorthand(Ident) => self.Read(Ident),
+			_ => Prop.visit_children_with(self),
+		}
+	}
+
+	/// Function bodies read the binding when called, wherever they are
+	/// defined.
+	fn visit_function(&mut self, Function:&Function) { Function.decorators.visit_with(self); }
+
+	fn visit_arrow_expr(&mut self, _:&ArrowExpr) {}
+}
+
+/// Whether `Position` lies inside `Span`.
+fn Within(Span:Span, Position:BytePos) -> bool { Span.lo <= Position && Position < Span.hi }
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use super::{
+	Analysis::{Analyzer, Binding, Boundary, Context},
+	Destructure::{Emptied, Member, Properties, Source},
+	Effect::{Literal, Order, Purity},
+	Statement::{Declared, Expression, Item},
+};
+use crate::Struct::Eliminate::Option::{Annotation, Dead, Struct as Option};
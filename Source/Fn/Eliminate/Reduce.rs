@@ -0,0 +1,483 @@
+/// How the code being visited runs relative to the body around it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+enum Run {
+	/// Whenever the body runs.
+	#[default]
+	Now,
+	/// Maybe not at all, as the branches of `?:` and the right side of `&&`,
+	/// `||` and `??`.
+	Maybe,
+	/// Later or repeatedly, inside a nested function or class.
+	Later,
+}
+
+/// Visitor that finds the reads and writes of the parameters of an arrow
+/// inside its body.
+struct Use {
+	/// The parameters.
+	Param:Vec<Id>,
+	/// Every read of each parameter, with how it runs.
+	Read:Vec<Vec<(Span, Run)>>,
+	/// Spans of the reads that are the callee of a call or `new`.
+	Callee:Vec<Span>,
+	/// Spans of the reads that are called or tag a template, where a member
+	/// access would pass its object as `this`.
+	Bound:Vec<Span>,
+	/// Whether the body writes any parameter.
+	Written:bool,
+	/// How the code being visited runs.
+	Run:Run,
+}
+
+impl Use {
+	/// Records a read of the given identifier.
+	fn Read(&mut self, Ident:&Ident) {
+		if let Some(Index) = self.Param.iter().position(|Param| *Param == Ident.to_id()) {
+			self.Read[Index].push((Ident.span, self.Run));
+		}
+	}
+
+	/// Records the callee of a call or the tag of a template.
+	fn Bound(&mut self, Callee:&Expr) {
+		if let Expr::Ident(Ident) = Callee.unwrap_parens() {
+			self.Bound.push(Ident.span);
+		}
+	}
+
+	/// Runs `Inner` for code that runs as `Run` says, or less often.
+	fn Enter(&mut self, Run:Run, Inner:impl FnOnce(&mut Self)) {
+		let Outer = self.Run;
+
+		self.Run = Outer.max(Run);
+
+		Inner(self);
+
+		self.Run = Outer;
+	}
+}
+
+impl Visit for Use {
+	fn visit_expr(&mut self, Expr:&Expr) {
+		match Expr {
+			Expr::Ident(Ident) => self.Read(Ident),
+			_ => Expr.visit_children_with(self),
+		}
+	}
+
+	fn visit_prop(&mut self, Prop:&Prop) {
+		match Prop {
+			Prop::Shorthand(Ident) => self.Read(Ident),
+			_ => Prop.visit_children_with(self),
+		}
+	}
+
+	/// Assignment targets, since the body is an expression.
+	fn visit_binding_ident(&mut self, Binding:&BindingIdent) {
+		self.Written |= self.Param.contains(&Binding.id.to_id());
+	}
+
+	fn visit_call_expr(&mut self, Call:&CallExpr) {
+		if let Callee::Expr(Callee) = &Call.callee {
+			if let Expr::Ident(Ident) = &**Callee {
+				self.Callee.push(Ident.span);
+			}
+
+			self.Bound(Callee);
+		}
+
+		Call.visit_children_with(self);
+	}
+
+	fn visit_opt_call(&mut self, Call:&OptCall) {
+		self.Bound(&Call.callee);
+
+		Call.visit_children_with(self);
+	}
+
+	fn visit_tagged_tpl(&mut self, Tagged:&TaggedTpl) {
+		self.Bound(&Tagged.tag);
+
+		Tagged.visit_children_with(self);
+	}
+
+	fn visit_new_expr(&mut self, New:&NewExpr) {
+		if let Expr::Ident(Ident) = &*New.callee {
+			self.Callee.push(Ident.span);
+		}
+
+		New.visit_children_with(self);
+	}
+
+	fn visit_update_expr(&mut self, Update:&UpdateExpr) {
+		if let Expr::Ident(Ident) = &*Update.arg {
+			self.Written |= self.Param.contains(&Ident.to_id());
+		}
+
+		Update.visit_children_with(self);
+	}
+
+	fn visit_assign_expr(&mut self, Assign:&AssignExpr) {
+		Assign.left.visit_with(self);
+
+		let Run = match Assign.op {
+			AssignOp::AndAssign | AssignOp::OrAssign | AssignOp::NullishAssign => Run::Maybe,
+			_ => Run::Now,
+		};
+
+		self.Enter(Run, |This| Assign.right.visit_with(This));
+	}
+
+	fn visit_bin_expr(&mut self, Bin:&BinExpr) {
+		Bin.left.visit_with(self);
+
+		let Run = match Bin.op {
+			BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => Run::Maybe,
+			_ => Run::Now,
+		};
+
+		self.Enter(Run, |This| Bin.right.visit_with(This));
+	}
+
+	fn visit_cond_expr(&mut self, Cond:&CondExpr) {
+		Cond.test.visit_with(self);
+
+		self.Enter(Run::Maybe, |This| {
+			Cond.cons.visit_with(This);
+
+			Cond.alt.visit_with(This);
+		});
+	}
+
+	fn visit_opt_chain_expr(&mut self, Chain:&OptChainExpr) {
+		self.Enter(Run::Maybe, |This| Chain.visit_children_with(This));
+	}
+
+	fn visit_function(&mut self, Function:&Function) {
+		self.Enter(Run::Later, |This| Function.visit_children_with(This));
+	}
+
+	fn visit_arrow_expr(&mut self, Arrow:&ArrowExpr) {
+		self.Enter(Run::Later, |This| Arrow.visit_children_with(This));
+	}
+
+	fn visit_class(&mut self, Class:&Class) {
+		self.Enter(Run::Later, |This| Class.visit_children_with(This));
+	}
+}
+
+/// Visitor that replaces the reads of parameters with their arguments.
+struct Bind<'b> {
+	/// How the type annotation of a parameter is kept.
+	Annotation:Annotation,
+	/// The argument of every parameter, with the parameter's type.
+	Value:HashMap<Id, (Expr, std::option::Option<Box<TsType>>)>,
+	/// Text edits reproducing the replacements.
+	Edits:&'b mut Vec<Edit>,
+}
+
+impl<'b> Bind<'b> {
+	/// The argument replacing `Ident` when it reads a parameter.
+	fn Argument(
+		&mut self,
+		Ident:&Ident,
+		Key:std::option::Option<String>,
+	) -> std::option::Option<Expr> {
+		let (Value, Type) = self.Value.get(&Ident.to_id())?.clone();
+
+		self.Edits.push(Edit::Replace {
+			Span:Ident.span,
+			Init:Text(&Value),
+			Key,
+			Member:None,
+			Type:Type.as_ref().map(|Type| (Keyword(self.Annotation), Type.span())),
+		});
+
+		Some(Annotate(self.Annotation, Value, Type, Ident.span))
+	}
+}
+
+impl<'b> VisitMut for Bind<'b> {
+	fn visit_mut_expr(&mut self, Expr:&mut Expr) {
+		if let Expr::Ident(Ident) = Expr
+			&& let Some(Argument) = self.Argument(Ident, None)
+		{
+			*Expr = Argument;
+
+			return;
+		}
+
+		Expr.visit_mut_children_with(self);
+	}
+
+	fn visit_mut_prop(&mut self, Prop:&mut Prop) {
+		if let Prop::Shorthand(Ident) = Prop {
+			let Ident = Ident.clone();
+
+			if let Some(Value) = self.Argument(&Ident, Some(Ident.sym.to_string())) {
+				let Key = IdentName::new(Ident.sym, Ident.span);
+
+				*Prop = Prop::KeyValue(KeyValueProp { key:PropName::Ident(Key), value:Box::new(Value) });
+
+				return;
+			}
+		}
+
+		Prop.visit_mut_children_with(self);
+	}
+}
+
+/// Beta-reduces immediately invoked arrows, such as the `((a) => a + 1)(x)`
+/// that inlining `const f = (a) => a + 1` into `f(x)` leaves, into their
+/// body with every parameter replaced by its argument: `x + 1`.
+///
+/// Only arrows reduce, since a `function` has its own `this` and
+/// `arguments`, and only plain, synchronous ones whose body is an expression
+/// or a single `return`, with a plain identifier for every parameter and
+/// exactly one argument, not spread, for each.
+///
+/// An argument replaces the reads of its parameter when that keeps when,
+/// how often and in which order side effects happen:
+///
+/// - An argument that is never read is dropped, so it must be pure and not
+///   an identifier, whose read may throw.
+/// - An identifier can be read any number of times, where the body always
+///   runs it and before any side effect of the body, and when it may be
+///   impure, as a global is, it must be the only impure argument.
+/// - Any other pure argument can be read anywhere in the body, and more than
+///   once when it is a literal or `this`. It only moves into a nested
+///   function or class when it is a literal and the `Literal` option allows
+///   literals across functions.
+/// - An impure argument must be the only one, read once, where the body
+///   always runs it and before any side effect of the body.
+/// - An anonymous function or arrow only replaces reads that call it, so it
+///   takes no name from its new place, and an anonymous class never moves.
+///
+/// The body never writes a parameter. The types of annotated parameters, and
+/// the return type, are kept as the `Annotation` option asks.
+pub struct Reducer<'a> {
+	/// The options controlling how far inlining may go.
+	Option:&'a Option,
+	/// Decides which arguments can be moved without reordering side effects.
+	Purity:Purity<'a>,
+	/// Whether any call was reduced.
+	pub Reduced:bool,
+	/// Text edits reproducing the reductions on the original source.
+	pub Edits:Vec<Edit>,
+}
+
+impl<'a> Reducer<'a> {
+	/// Creates a new `Reducer` with the given options and purity rules.
+	pub fn New(Option:&'a Option, Purity:Purity<'a>) -> Self {
+		Self { Option, Purity, Reduced:false, Edits:Vec::new() }
+	}
+
+	/// Whether the argument can replace the given reads of its parameter in
+	/// `Body`. `Callee` holds the reads that are the callee of a call or
+	/// `new`, `Bound` those that are called or tag a template, and `Only`
+	/// tells whether the argument is the only impure one.
+	fn Movable(
+		&self,
+		Argument:&Expr,
+		Read:&[(Span, Run)],
+		Callee:&[Span],
+		Bound:&[Span],
+		Body:&Expr,
+		Only:bool,
+	) -> bool {
+		let Pure = self.Purity.Expr(Argument);
+
+		let Plain = Strip(Argument);
+
+		let Literal = Literal(Plain);
+
+		// Where the argument runs, ahead of every side effect of the body.
+		let Ahead = |(Span, Run):&(Span, Run)| *Run == Run::Now && !self.Purity.Before(Body, Span.lo);
+
+		// A member access called in place of the parameter would pass its
+		// object as `this`.
+		if self.Purity.Receiver(Plain) && Read.iter().any(|(Span, _)| Bound.contains(Span)) {
+			return false;
+		}
+
+		match Plain {
+			// A function or an arrow takes a name from where it is put unless
+			// it is called there, and a class can read its own while defined.
+			Expr::Arrow(_) | Expr::Fn(FnExpr { ident: None, .. })
+				if !Read.iter().all(|(Span, _)| Callee.contains(Span)) =>
+			{
+				return false;
+			},
+			Expr::Class(ClassExpr { ident: None, .. }) => return false,
+			// An identifier throws when undeclared or in its temporal dead
+			// zone, so its first read stays where the argument ran, and every
+			// read sees the same value ahead of the side effects of the body.
+			Expr::Ident(_) => return !Read.is_empty() && (Pure || Only) && Read.iter().all(Ahead),
+			_ => {},
+		}
+
+		match Read {
+			[] => Pure,
+			[(Span, Run::Now)] if !Pure => Only && !self.Purity.Before(Body, Span.lo),
+			_ if !Pure => false,
+			_ => {
+				let Later = self.Option.Literal && Literal;
+
+				(Read.len() == 1 || Literal || matches!(Plain, Expr::This(_)))
+					&& Read.iter().all(|(_, Run)| *Run != Run::Later || Later)
+			},
+		}
+	}
+
+	/// The body of the call with every parameter replaced by its argument,
+	/// when the call is an immediately invoked arrow that can be reduced.
+	fn Reduce(&mut self, Call:&CallExpr) -> std::option::Option<Expr> {
+		let Callee::Expr(Callee) = &Call.callee else {
+			return None;
+		};
+
+		let Expr::Arrow(Arrow) = Callee.unwrap_parens() else {
+			return None;
+		};
+
+		if Arrow.is_async
+			|| Arrow.is_generator
+			|| Arrow.type_params.is_some()
+			|| Call.type_args.is_some()
+		{
+			return None;
+		}
+
+		let Body = match &*Arrow.body {
+			BlockStmtOrExpr::Expr(Body) => &**Body,
+			BlockStmtOrExpr::BlockStmt(BlockStmt { stmts, .. }) => {
+				match &stmts[..] {
+					[Stmt::Return(ReturnStmt { arg: Some(Body), .. })] => &**Body,
+					_ => return None,
+				}
+			},
+		};
+
+		let Param = Arrow
+			.params
+			.iter()
+			.map(|Param| {
+				match Param {
+					Pat::Ident(Binding) => Some(Binding),
+					_ => None,
+				}
+			})
+			.collect::<std::option::Option<Vec<_>>>()?;
+
+		if Param.len() != Call.args.len() || Call.args.iter().any(|Arg| Arg.spread.is_some()) {
+			return None;
+		}
+
+		let Return = Arrow.return_type.as_ref().map(|Return| Return.type_ann.clone());
+
+		let Typed = Return.is_some() || Param.iter().any(|Param| Param.type_ann.is_some());
+
+		if Typed && self.Option.Annotation == Annotation::Refuse {
+			return None;
+		}
+
+		// Every replacement needs text in the source.
+		if Call.span.is_dummy()
+			|| Text(Body).is_dummy()
+			|| Call.args.iter().any(|Arg| Text(&Arg.expr).is_dummy())
+		{
+			return None;
+		}
+
+		let mut Use = Use {
+			Param:Param.iter().map(|Param| Param.id.to_id()).collect(),
+			Read:vec![Vec::new(); Param.len()],
+			Callee:Vec::new(),
+			Bound:Vec::new(),
+			Written:false,
+			Run:Run::Now,
+		};
+
+		Body.visit_with(&mut Use);
+
+		if Use.Written {
+			return None;
+		}
+
+		let Only = Call.args.iter().filter(|Arg| !self.Purity.Expr(&Arg.expr)).count() <= 1;
+
+		let Movable = Call
+			.args
+			.iter()
+			.zip(&Use.Read)
+			.all(|(Arg, Read)| self.Movable(&Arg.expr, Read, &Use.Callee, &Use.Bound, Body, Only));
+
+		if !Movable {
+			return None;
+		}
+
+		let mut Bind = Bind {
+			Annotation:self.Option.Annotation,
+			Value:Param
+				.iter()
+				.zip(&Call.args)
+				.map(|(Param, Arg)| {
+					let Type = Param.type_ann.as_ref().map(|Type| Type.type_ann.clone());
+
+					(Param.id.to_id(), (*Arg.expr.clone(), Type))
+				})
+				.collect(),
+			Edits:&mut self.Edits,
+		};
+
+		let mut Body = Body.clone();
+
+		let Init = Text(&Body);
+
+		Body.visit_mut_with(&mut Bind);
+
+		self.Edits.push(Edit::Replace {
+			Span:Call.span,
+			Init,
+			Key:None,
+			Member:None,
+			Type:Return.as_ref().map(|Type| (Keyword(self.Option.Annotation), Type.span())),
+		});
+
+		Some(Annotate(self.Option.Annotation, Body, Return, Call.span))
+	}
+}
+
+impl<'a> VisitMut for Reducer<'a> {
+	/// Reduces the call once the calls inside it are reduced.
+	fn visit_mut_expr(&mut self, Expr:&mut Expr) {
+		Expr.visit_mut_children_with(self);
+
+		if let Expr::Call(Call) = Expr
+			&& let Some(Body) = self.Reduce(Call)
+		{
+			*Expr = Body;
+
+			self.Reduced = true;
+		}
+	}
+}
+
+/// The expression inside the parentheses the Inliner added.
+fn Strip(Expr:&Expr) -> &Expr {
+	match Expr {
+		Expr::Paren(ParenExpr { span, expr }) if span.is_dummy() => Strip(expr),
+		_ => Expr,
+	}
+}
+
+/// Span of the source text of the expression.
+pub fn Text(Expr:&Expr) -> Span { Strip(Expr).span() }
+
+use std::collections::HashMap;
+
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use super::{Annotate, Effect::{Literal, Purity}, Keyword, Text::Edit};
+use crate::Struct::Eliminate::Option::{Annotation, Struct as Option};
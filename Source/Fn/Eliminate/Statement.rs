@@ -0,0 +1,133 @@
+/// An entry of a statement list the Inliner can eliminate declarations from:
+/// a module item at the top level or in a namespace, or a statement in a
+/// function, arrow, method or block body.
+pub trait Item: Spanned {
+	/// The variable declaration this entry consists of, if any.
+	fn Var(&self) -> Option<&VarDecl>;
+
+	/// The variable declaration this entry consists of, if any, for
+	/// rewriting.
+	fn VarMut(&mut self) -> Option<&mut VarDecl>;
+
+	/// The function or class declaration this entry consists of, if it can
+	/// stand as an expression: one with a body, neither ambient, abstract
+	/// nor decorated.
+	fn Callable(&self) -> Option<&Decl>;
+
+	/// The expression this entry evaluates, if it is an expression
+	/// statement.
+	fn Evaluated(&self) -> Option<&Expr>;
+
+	/// Whether executing the entry can have no observable side effects.
+	fn Pure(&self, Purity:&Purity) -> bool;
+
+	/// An entry that evaluates `Expr` and discards its value.
+	fn Discard(Expr:Box<Expr>, Span:Span) -> Self
+	where
+		Self: Sized;
+}
+
+/// The name a function or class declaration binds.
+pub fn Declared(Decl:&Decl) -> Option<&Ident> {
+	match Decl {
+		Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => Some(ident),
+		_ => None,
+	}
+}
+
+/// The named function or class expression equivalent to a declaration, which
+/// keeps its `name`, its own `this` and `arguments`, and any reference to
+/// itself from inside.
+pub fn Expression(Decl:&Decl) -> Option<Expr> {
+	match Decl {
+		Decl::Fn(FnDecl { ident, function, .. }) => {
+			Some(Expr::Fn(FnExpr { ident:Some(ident.clone()), function:function.clone() }))
+		},
+		Decl::Class(ClassDecl { ident, class, .. }) => {
+			Some(Expr::Class(ClassExpr { ident:Some(ident.clone()), class:class.clone() }))
+		},
+		_ => None,
+	}
+}
+
+impl Item for Stmt {
+	fn Var(&self) -> Option<&VarDecl> {
+		match self {
+			Stmt::Decl(Decl::Var(VarDecl)) => Some(&**VarDecl),
+			_ => None,
+		}
+	}
+
+	fn VarMut(&mut self) -> Option<&mut VarDecl> {
+		match self {
+			Stmt::Decl(Decl::Var(VarDecl)) => Some(&mut **VarDecl),
+			_ => None,
+		}
+	}
+
+	fn Callable(&self) -> Option<&Decl> {
+		match self {
+			Stmt::Decl(Decl @ Decl::Fn(FnDecl { declare:false, function, .. }))
+				if function.body.is_some() =>
+			{
+				Some(Decl)
+			},
+			Stmt::Decl(Decl @ Decl::Class(ClassDecl { declare:false, class, .. }))
+				if !class.is_abstract && class.decorators.is_empty() =>
+			{
+				Some(Decl)
+			},
+			_ => None,
+		}
+	}
+
+	fn Evaluated(&self) -> Option<&Expr> {
+		match self {
+			Stmt::Expr(ExprStmt { expr, .. }) => Some(expr),
+			_ => None,
+		}
+	}
+
+	fn Pure(&self, Purity:&Purity) -> bool { Purity.Stmt(self) }
+
+	fn Discard(Expr:Box<Expr>, Span:Span) -> Self { Stmt::Expr(ExprStmt { span:Span, expr:Expr }) }
+}
+
+impl Item for ModuleItem {
+	fn Var(&self) -> Option<&VarDecl> {
+		match self {
+			ModuleItem::Stmt(Stmt) => Stmt.Var(),
+			ModuleItem::ModuleDecl(_) => None,
+		}
+	}
+
+	fn VarMut(&mut self) -> Option<&mut VarDecl> {
+		match self {
+			ModuleItem::Stmt(Stmt) => Stmt.VarMut(),
+			ModuleItem::ModuleDecl(_) => None,
+		}
+	}
+
+	fn Callable(&self) -> Option<&Decl> {
+		match self {
+			ModuleItem::Stmt(Stmt) => Stmt.Callable(),
+			ModuleItem::ModuleDecl(_) => None,
+		}
+	}
+
+	fn Evaluated(&self) -> Option<&Expr> {
+		match self {
+			ModuleItem::Stmt(Stmt) => Stmt.Evaluated(),
+			ModuleItem::ModuleDecl(_) => None,
+		}
+	}
+
+	fn Pure(&self, Purity:&Purity) -> bool { Purity.ModuleItem(self) }
+
+	fn Discard(Expr:Box<Expr>, Span:Span) -> Self { ModuleItem::Stmt(Stmt::Discard(Expr, Span)) }
+}
+
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::*;
+
+use super::Effect::Purity;
@@ -119,3 +119,863 @@
 
 // 	Ok(())
 // }
+
+/// Runs the eliminator over the given TypeScript module and returns the
+/// printed output.
+fn Eliminate(Code:&str) -> String { EliminateWith(Code, &Option::default()) }
+
+/// Runs the eliminator over a module with the given options.
+fn EliminateWith(Code:&str, Option:&Option) -> String { EliminateAs(".mts", Code, Option) }
+
+/// Runs the eliminator over a file with the given suffix, which picks the
+/// dialect and whether a file without `import` or `export` is a script.
+fn EliminateAs(Suffix:&str, Code:&str, Option:&Option) -> String {
+	let File = Builder::new().suffix(Suffix).tempfile().expect("Cannot tempfile.");
+
+	fs::write(File.path(), Code).expect("Cannot write.");
+
+	ProcessFileRecursive(File.path(), Option).expect("Cannot ProcessFileRecursive.").0
+}
+
+#[test]
+fn InlineSingleUse() {
+	let Result = Eliminate("const a = 5; const b = a + 1; console.log(b);");
+
+	assert!(Result.contains("console.log(5 + 1)"));
+
+	assert!(!Result.contains("const a"));
+
+	assert!(!Result.contains("const b"));
+}
+
+#[test]
+fn KeepMultipleUse() {
+	let Result = Eliminate("const a = compute(); f(a); g(a);");
+
+	assert!(Result.contains("const a = compute()"));
+
+	assert!(Result.contains("f(a)"));
+
+	assert!(Result.contains("g(a)"));
+}
+
+#[test]
+fn ShadowedBindingsAreIndependent() {
+	let Result = Eliminate(
+		"const x = 1; function f() { const x = 2; return x + x; } console.log(x, f());",
+	);
+
+	assert!(Result.contains("console.log(1, f())"));
+
+	assert!(Result.contains("const x = 2"));
+}
+
+#[test]
+fn KeepImpureInitializerAcrossSideEffects() {
+	let Result = Eliminate("const r = fetchThing(); doOther(); use(r);");
+
+	assert!(Result.contains("const r = fetchThing()"));
+
+	assert!(Result.contains("use(r)"));
+}
+
+#[test]
+fn MoveImpureInitializerWithoutSideEffectsBetween() {
+	let Result = Eliminate("const r = fetchThing(); use(r);");
+
+	assert!(Result.contains("use(fetchThing())"));
+}
+
+#[test]
+fn KeepReadsOfWrittenBindingsBeforeWrites() {
+	let Result = Eliminate("function f() { let a = 1; const s = a + 1; a = 5; return s; }");
+
+	assert!(Result.contains("const s = a + 1"));
+
+	let Result = Eliminate("function g(p) { const s = p; p = 5; return s; }");
+
+	assert!(Result.contains("const s = p"));
+
+	let Result = Eliminate(
+		"let count = 0; function inc() { count++; } \
+		 export function f() { const before = count; inc(); inc(); return before; }",
+	);
+
+	assert!(Result.contains("const before = count"));
+
+	let Result = Eliminate("let a = 1; a = 2; const s = a + 1; log(s);");
+
+	assert!(Result.contains("log(a + 1)"));
+}
+
+#[test]
+fn KeepReassignedBinding() {
+	let Result = Eliminate("let x = 1; x = 2; f(x);");
+
+	assert!(Result.contains("let x = 1"));
+
+	let Result = Eliminate("let y = 1; [y] = list; f(y);");
+
+	assert!(Result.contains("let y = 1"));
+
+	let Result = Eliminate("let z = 1; for (z of list) {} f(z);");
+
+	assert!(Result.contains("let z = 1"));
+
+	let Result = Eliminate("let w = 1; const g = () => { w += 1; }; g(); f(w);");
+
+	assert!(Result.contains("let w = 1"));
+}
+
+#[test]
+fn KeepInitializerOutOfLoopsAndClosures() {
+	let Result = Eliminate("const o = {}; for (const k of list) push(o);");
+
+	assert!(Result.contains("const o = {}"));
+
+	let Result = Eliminate("const t = Date.now(); setTimeout(() => log(t));");
+
+	assert!(Result.contains("const t = Date.now()"));
+
+	let Result = Eliminate("const r = load(); if (ready) use(r);");
+
+	assert!(Result.contains("const r = load()"));
+}
+
+#[test]
+fn MoveLiteralAcrossBoundaryWhenAllowed() {
+	let Code = "const n = 5; list.forEach((x) => log(x, n));";
+
+	assert!(Eliminate(Code).contains("const n = 5"));
+
+	assert!(
+		EliminateWith(Code, &Option { Literal:true, ..Option::default() }).contains("log(x, 5)")
+	);
+}
+
+#[test]
+fn KeepExportedBindings() {
+	for Code in [
+		"export const x = load(); use(x);",
+		"const x = load(); use(x); export default x;",
+		"const x = load(); use(x); export { x as y };",
+		"const x = load(); use(x); export = x;",
+	] {
+		assert!(Eliminate(Code).contains("use(x)"), "{}", Code);
+	}
+}
+
+#[test]
+fn InlineInsideNestedScopes() {
+	let Result = Eliminate("function f() { const result = compute(); return result; }");
+
+	assert!(Result.contains("return compute()"));
+
+	assert!(!Result.contains("const result"));
+
+	let Result = Eliminate("namespace N { const k = 1; log(k); }");
+
+	assert!(Result.contains("log(1)"));
+}
+
+#[test]
+fn RemoveSingleDeclarator() {
+	let Result = Eliminate("let a = 1, b = compute(); f(a); g(b); h(b);");
+
+	assert!(Result.contains("let b = compute()"));
+
+	assert!(Result.contains("f(1)"));
+
+	let Result = Eliminate("let a = 1, b = 2; f(a, b);");
+
+	assert!(Result.contains("f(1, 2)"));
+
+	assert!(!Result.contains("let"));
+}
+
+#[test]
+fn ParenthesizeByPrecedence() {
+	for (Code, Expected) in [
+		("const s = a + b; f(s * 2);", "f((a + b) * 2)"),
+		("const s = a - b; f(c - s);", "f(c - (a - b))"),
+		("const s = a - b; f(s - c);", "f(a - b - c)"),
+		("const s = -a; f(s ** 2);", "f((-a) ** 2)"),
+		("const s = a || b; f(s ?? c);", "f((a || b) ?? c)"),
+		("const s = a ? b : c; f(s.d);", "f((a ? b : c).d)"),
+		("const s = a?.(); f(s());", "f((a?.())())"),
+		("const s = g(); f(new s());", "f(new (g())())"),
+		("async function h() { const s = a && b; return await s; }", "await (a && b)"),
+		("const s = { a: 1 }; s.a;", "}).a"),
+		("const s = 5; f(s.toFixed());", "f((5).toFixed())"),
+		("const d = 9; const { a = d } = xs; f(a);", "const { a = 9 } = xs"),
+		("const s = h(); for (s; ;) f();", "for (h(); ;)"),
+		("const s = a in b; for (s; ;) f();", "for ((a in b); ;)"),
+	] {
+		let Result = Eliminate(Code);
+
+		assert!(Result.contains(Expected), "{} => {}", Code, Result);
+	}
+
+	for (Code, Expected) in [
+		("const v = 3; class A { f = v; } f(new A());", "f = 3;"),
+		("const v = 3; class A { #f = v; g() { return this.#f; } } f(new A());", "#f = 3;"),
+		("const n = 5; for (let i = 0; i < n; i++) f(i);", "i < 5;"),
+	] {
+		let Result = EliminateWith(Code, &Option { Literal:true, ..Option::default() });
+
+		assert!(Result.contains(Expected), "{} => {}", Code, Result);
+	}
+}
+
+#[test]
+fn KeepReceiverOfCalledMembers() {
+	for Code in [
+		"const m = obj.method; log(m());",
+		"const m = obj.method; log(m`x`);",
+		"const m = obj?.method; log(m?.());",
+		"const m = obj.method; const f = m; log(f());",
+	] {
+		let Result = Eliminate(Code);
+
+		assert!(Result.contains("const m = obj"), "{} => {}", Code, Result);
+	}
+
+	let Result = Eliminate("log(((f) => f())(obj.method));");
+
+	assert!(Result.contains("((f) => f())(obj.method)"));
+
+	let Result = Eliminate("const C = ns.Klass; log(new C());");
+
+	assert!(Result.contains("log(new ns.Klass())"));
+}
+
+#[test]
+fn DecideConsumersBeforeProducers() {
+	let Result = Eliminate("const a = load(); const b = a; sideEffect(); use(b);");
+
+	assert!(Result.contains("const a = load()"));
+
+	assert!(Result.contains("use(a)"));
+
+	let Result = Eliminate("const x = load(); const y = x + 1; if (c) log(y);");
+
+	assert!(Result.contains("const x = load()"));
+
+	assert!(Result.contains("log(x + 1)"));
+
+	let Result = Eliminate("const q = a(); const r = b(); const x = [r, q]; use(x);");
+
+	assert!(Result.contains("const q = a()"));
+
+	assert!(Result.contains("use([b(), q])"));
+}
+
+#[test]
+fn ResolveChainInSingleRun() {
+	let mut Code = String::from("const a0 = load();");
+
+	for Index in 1..26 {
+		Code.push_str(&format!(" const a{} = [a{}];", Index, Index - 1));
+	}
+
+	Code.push_str(" use(a25);");
+
+	let File = Builder::new().suffix(".mts").tempfile().expect("Cannot tempfile.");
+
+	fs::write(File.path(), Code).expect("Cannot write.");
+
+	let (Result, Diagnostic) = ProcessFileRecursive(File.path(), &Option::default())
+		.expect("Cannot ProcessFileRecursive.");
+
+	assert!(Result.contains(&format!("use({}load(){})", "[".repeat(25), "]".repeat(25))));
+
+	// The first run inlines the whole chain, the second only confirms it and
+	// is not counted.
+	assert_eq!(Diagnostic.Pass, 1);
+}
+
+#[test]
+fn PreserveComments() {
+	let Result = Eliminate("/*! License */\nconst a = load();\n// @ts-expect-error\nuse(a);");
+
+	assert!(Result.contains("/*! License */"));
+
+	assert!(Result.contains("// @ts-expect-error"));
+
+	assert!(Result.find("License") < Result.find("use("));
+
+	let Result = Eliminate("g();\n/* Note */ const a = 1; f(a);");
+
+	assert!(Result.find("Note") > Result.find("f("));
+
+	let Result = EliminateWith(
+		"g();\n/* Note */ const a = 1; f(a);",
+		&Option { Comment:Placement::Keep, ..Option::default() },
+	);
+
+	assert!(Result.find("Note") < Result.find("f("));
+
+	// The header of the file and directives for the next line stay.
+	assert_eq!(
+		Eliminate("/* Copyright (c) Acme */\nconst a = compute();\nuse(a);\n"),
+		"/* Copyright (c) Acme */\nuse(compute());\n"
+	);
+
+	assert_eq!(
+		Eliminate("// License: MIT\nconst a = compute();\nuse(a);\n"),
+		"// License: MIT\nuse(compute());\n"
+	);
+
+	assert_eq!(
+		Eliminate("f();\n// @ts-expect-error\nconst a = compute();\nuse(a);\n"),
+		"f();\n// @ts-expect-error\nuse(compute());\n"
+	);
+
+	assert_eq!(
+		Eliminate("f();\n/* eslint-disable-next-line */\nconst a = compute();\nuse(a);\n"),
+		"f();\n/* eslint-disable-next-line */\nuse(compute());\n"
+	);
+}
+
+#[test]
+fn EditOnlyChangedText() {
+	assert_eq!(
+		Eliminate("const a   =   'x';\nlet   keep = \"y\"\nf(a,   keep, keep)\n"),
+		"let   keep = \"y\"\nf('x',   keep, keep)\n"
+	);
+
+	assert_eq!(Eliminate("const x = load();\nsend({ x });\n"), "send({ x: load() });\n");
+
+	// A replacement starting a statement must not continue the line before.
+	assert_eq!(
+		Eliminate("let b = 1\nconst a = [1, 2]\na.forEach(f)\n"),
+		"let b = 1\n;[1, 2].forEach(f)\n"
+	);
+
+	assert_eq!(
+		Eliminate("let b = g()\nconst a = (x, y)\na.forEach(f)\n"),
+		"let b = g()\n;(x, y).forEach(f)\n"
+	);
+
+	assert_eq!(
+		Eliminate("let b = 1;\nconst a = [1, 2];\na.forEach(f);\n"),
+		"let b = 1;\n[1, 2].forEach(f);\n"
+	);
+
+	assert_eq!(Eliminate("const a = x // note */ here\nf(a)\n"), "f(// note */ here\nx)\n");
+
+	assert_eq!(Eliminate("const a = x; // x\nf(a);\n"), "f(// x\nx);\n");
+
+	assert_eq!(Eliminate("const a = /*  y */ x;\nf(a);\n"), "f(/*  y */ x);\n");
+
+	let Result = EliminateWith(
+		"const a   =   'x';\nf(a)\n",
+		&Option { Reprint:true, ..Option::default() },
+	);
+
+	assert!(Result.contains("f('x');"), "{}", Result);
+}
+
+#[test]
+fn PreserveLayout() {
+	let Code = "\u{feff}function f() {\r\n\tconst a = 1;\r\n\tlog(a);\r\n}\r\n";
+
+	assert_eq!(Eliminate(Code), "\u{feff}function f() {\r\n\tlog(1);\r\n}\r\n");
+
+	let Result = EliminateWith(Code, &Option { Reprint:true, ..Option::default() });
+
+	assert!(Result.starts_with('\u{feff}'), "{:?}", Result);
+
+	assert!(Result.contains("{\r\n\tlog(1);\r\n}"), "{:?}", Result);
+
+	let Result = EliminateWith(
+		"if (a) {\n  if (b) {\n    const c = 1;\n    log(c);\n  }\n}\n",
+		&Option { Reprint:true, ..Option::default() },
+	);
+
+	assert!(Result.contains("\n    log(1);\n  }"), "{:?}", Result);
+}
+
+#[test]
+fn SelectDialectByExtension() {
+	let Run = |Suffix:&str, Code:&str, Option:&Option| {
+		let File = Builder::new().suffix(Suffix).tempfile().expect("Cannot tempfile.");
+
+		fs::write(File.path(), Code).expect("Cannot write.");
+
+		ProcessFileRecursive(File.path(), Option).map(|(Result, _)| Result)
+	};
+
+	let Component = "function g() { const el = <div className=\"a\" />; render(el); }";
+
+	for Suffix in [".tsx", ".jsx", ".js"] {
+		let Result = Run(Suffix, Component, &Option::default()).expect("Cannot ProcessFileRecursive.");
+
+		assert!(Result.contains("render(<div className=\"a\" />)"), "{} => {}", Suffix, Result);
+	}
+
+	assert!(Run(".ts", Component, &Option::default()).is_err());
+
+	assert!(Run(".ts", Component, &Option { Dialect:Dialect::Tsx, ..Option::default() }).is_ok());
+
+	let Result = Run(".mts", "const n = 1 as number; f(n);", &Option::default())
+		.expect("Cannot ProcessFileRecursive.");
+
+	assert!(Result.contains("f(1 as number)"), "{}", Result);
+}
+
+#[test]
+fn ParseScriptsAndKeepCommonJsExports() {
+	let Run = |Suffix:&str, Code:&str| {
+		let File = Builder::new().suffix(Suffix).tempfile().expect("Cannot tempfile.");
+
+		fs::write(File.path(), Code).expect("Cannot write.");
+
+		ProcessFileRecursive(File.path(), &Option::default())
+	};
+
+	// TypeScript rejects legacy octal literals, so the sloppy script is `.js`.
+	let (Result, _) =
+		Run(".js", "function g() { var a = 010; f(a); }").expect("Cannot ProcessFileRecursive.");
+
+	assert!(Result.contains("f(010)"), "{}", Result);
+
+	// Other scripts can use the top-level bindings of a script as globals.
+	let (Result, _) =
+		Run(".js", "var config = { a: 1 }; init(config);").expect("Cannot ProcessFileRecursive.");
+
+	assert!(Result.contains("var config = { a: 1 }"), "{}", Result);
+
+	assert!(Run(".ts", "var a = 010; f(a);").is_err());
+
+	assert!(Run(".mjs", "var a = 010; f(a);").is_err());
+
+	for Code in [
+		"const x = load(); module.exports = x;",
+		"const x = load(); module.exports = { x };",
+		"const x = load(); module.exports = { y: x };",
+		"const x = load(); exports.y = x;",
+		"const x = load(); module.exports.y = x;",
+	] {
+		let (Result, _) = Run(".cjs", Code).expect("Cannot ProcessFileRecursive.");
+
+		assert!(Result.contains("const x = load()"), "{} => {}", Code, Result);
+	}
+}
+
+#[test]
+fn KeepBindingsReferencedFromTypes() {
+	for Code in [
+		"const config = { a: 1 }; type Config = typeof config; use(config);",
+		"const config = { a: 1 }; type Key = keyof typeof config; use(config);",
+		"const config = { a: 1 }; use(config); let k: typeof config.a;",
+		"const config = { a: 1 }; use<typeof config>(config);",
+	] {
+		assert!(Eliminate(Code).contains("const config = {"), "{}", Code);
+	}
+}
+
+#[test]
+fn KeepTypeAnnotations() {
+	let Code = "const port: number = env.PORT as any; listen(port);";
+
+	assert!(Eliminate(Code).contains("listen(env.PORT as any satisfies number)"));
+
+	let Result = EliminateWith(Code, &Option { Annotation:Annotation::As, ..Option::default() });
+
+	assert!(Result.contains("listen(env.PORT as any as number)"), "{}", Result);
+
+	let Result = EliminateWith(Code, &Option { Annotation:Annotation::Refuse, ..Option::default() });
+
+	assert!(Result.contains("const port: number"), "{}", Result);
+
+	let Result = Eliminate("const s: number = a + b; f(s * 2);");
+
+	assert!(Result.contains("f((a + b satisfies number) * 2)"), "{}", Result);
+
+	let Result = Eliminate("const s: number = a + b; const o = { s }; f(o);");
+
+	assert!(Result.contains("f({ s: a + b satisfies number })"), "{}", Result);
+}
+
+#[test]
+fn BailOutOfDynamicScopes() {
+	assert!(Eliminate("function f() { const b = 2; eval(s); return b; }").contains("const b = 2"));
+
+	let Result = Eliminate(
+		"const a = 1; log(a); function g() { eval(s); } function h() { const c = 3; return c; }",
+	);
+
+	assert!(Result.contains("const a = 1"));
+
+	assert!(Result.contains("return 3"));
+
+	// An indirect `eval` stays indirect.
+	assert!(Eliminate("const e = eval; log(e(s));").contains("const e = eval"));
+
+	assert!(Eliminate("log(((f) => f(s))(eval));").contains("((f) => f(s))(eval)"));
+
+	// `with` and aliased `arguments` only exist in sloppy-mode scripts, and
+	// TypeScript rejects `with`, so that statement is read as JavaScript.
+	let Javascript = Option { Dialect:Dialect::Javascript, ..Option::default() };
+
+	assert!(
+		EliminateAs(".ts", "function f(o) { const b = 2; with (o) { log(b); } }", &Javascript)
+			.contains("const b = 2")
+	);
+
+	let Script = |Code:&str| EliminateAs(".ts", Code, &Option::default());
+
+	assert!(Script("function f(a) { const b = 1; arguments[0] = b; return a; }").contains("const b = 1"));
+
+	assert!(
+		Script("function f(a) { 'use strict'; const b = 1; arguments[0] = b; return a; }")
+			.contains("arguments[0] = 1")
+	);
+
+	let File = Builder::new().suffix(".ts").tempfile().expect("Cannot tempfile.");
+
+	fs::write(File.path(), "const a = 1;\nlog(a, eval(s));").expect("Cannot write.");
+
+	let (_, Diagnostic) = ProcessFileRecursive(File.path(), &Option::default())
+		.expect("Cannot ProcessFileRecursive.");
+
+	assert_eq!(Diagnostic.Bailout, vec![Bailout { Reason:Reason::Eval, Line:2 }]);
+}
+
+#[test]
+fn RespectHoistingAndTemporalDeadZone() {
+	let Literal = Option { Literal:true, ..Option::default() };
+
+	for (Code, Kept) in [
+		("f(); var x = 5; function f() { return x; }", "var x = 5"),
+		("const x = 5; function f() { return x; } f();", "const x = 5"),
+		("const a = b; const b = 1; log(a);", "const a = b"),
+		("var a = b; var b = 1; log(a);", "var a = b"),
+		("switch (k) { case 0: const x = 1; case 1: log(x); }", "const x = 1"),
+		("a: { if (c) break a; var x = 1; } log(x);", "var x = 1"),
+		("const r = load(); const y = z; use(r, y); let z = 1;", "const r = load()"),
+	] {
+		let Result = EliminateWith(Code, &Literal);
+
+		assert!(Result.contains(Kept), "{} => {}", Code, Result);
+	}
+
+	// The arrow is then reduced at its call.
+	assert_eq!(EliminateWith("const x = 5; const f = () => x; f();", &Literal), "5;");
+
+	assert!(Eliminate("const b = 1; const a = () => c; const c = b; log(a());").contains("log(c)"));
+
+	assert!(Eliminate("const a = () => c; log(a()); const c = 1;").contains("const c = 1"));
+}
+
+#[test]
+fn DestructureIntoMemberAccess() {
+	for (Code, Expected, Gone) in [
+		("const { a, b: c } = props; use(a, c);", "use(props.a, props.b)", "const {"),
+		("const { b, a } = props; use(a); log(b, b);", "const { b } = props;", "use(a)"),
+		("const { \"x-y\": z, 0: w } = props; f(z, w);", "f(props[\"x-y\"], props[0])", "const {"),
+		("const { a } = props; f({ a });", "f({ a: props.a })", "const {"),
+		("const props = load(); const { a } = props; f(a);", "f(load().a)", "const"),
+	] {
+		let Result = Eliminate(Code);
+
+		assert!(Result.contains(Expected), "{} => {}", Code, Result);
+
+		assert!(!Result.contains(Gone), "{} => {}", Code, Result);
+	}
+
+	for (Code, Kept) in [
+		("const { a, ...r } = props; f(a); g(r);", "const { a, ...r } = props"),
+		("const { a = 1 } = props; f(a);", "const { a = 1 } = props"),
+		("const { a } = load(); f(a);", "const { a } = load()"),
+		("const { a, b } = props; use(a); log(b, b);", "const { a, b } = props"),
+		("const [a] = list; f(a);", "const [a] = list"),
+		("const { method } = obj; log(method());", "const { method } = obj"),
+		("const { method } = obj; log(method`x`);", "const { method } = obj"),
+		("const { a, method } = obj; log(a, method());", "method } = obj"),
+	] {
+		let Result = Eliminate(Code);
+
+		assert!(Result.contains(Kept), "{} => {}", Code, Result);
+	}
+}
+
+#[test]
+fn InlineFunctionAndClassDeclarations() {
+	let Function = Option { Function:true, ..Option::default() };
+
+	let Result = EliminateWith("function key(x) { return x + 1; } use(key(2));", &Function);
+
+	assert!(Result.contains("use((function key(x) { return x + 1; })(2))"), "{}", Result);
+
+	assert_eq!(
+		EliminateWith("function f() { return 1; }\nf();\n", &Function),
+		"(function f() { return 1; })();\n"
+	);
+
+	let Result = EliminateWith("class Point { constructor(x) { this.x = x; } } log(new Point(1));", &Function);
+
+	assert!(Result.contains("log(new") && Result.contains("class Point {"), "{}", Result);
+
+	assert!(!Result.starts_with("class"), "{}", Result);
+
+	for (Code, Kept) in [
+		("f(); function f() { return 1; }", "function f()"),
+		("function fact(n) { return n ? n * fact(n - 1) : 1; } log(fact(5));", "function fact(n)"),
+		("function f() { return 1; } for (const x of xs) f();", "function f()"),
+		("function f() { return 1; } const g = () => f(); g();", "function f()"),
+		("class A {} const a: A = make(); log(a, new A());", "class A {}"),
+		("export function f() { return 1; } f();", "export function f()"),
+	] {
+		let Result = EliminateWith(Code, &Function);
+
+		assert!(Result.contains(Kept), "{} => {}", Code, Result);
+	}
+
+	assert!(Eliminate("function f() { return 1; } log(f());").contains("function f()"));
+}
+
+#[test]
+fn KeepNamesOfAnonymousFunctions() {
+	// The arrow moves into the callee, where it is then reduced.
+	assert!(Eliminate("const fmt = (x) => x + 1; log(fmt(2));").contains("log(2 + 1)"));
+
+	for (Code, Kept) in [
+		("const fmt = (x) => x + 1; register(fmt);", "const fmt = (x) => x + 1"),
+		("const fmt = function () {}; log(fmt.name);", "const fmt = function () {}"),
+		("const C = class {}; log(new C());", "const C = class {}"),
+	] {
+		let Result = Eliminate(Code);
+
+		assert!(Result.contains(Kept), "{} => {}", Code, Result);
+	}
+}
+
+#[test]
+fn ReduceImmediatelyInvokedArrows() {
+	for (Code, Expected) in [
+		("const f = (a) => a + 1; log(f(x));", "log(x + 1)"),
+		("const f = (a) => a + 1; log(2 * f(x));", "log(2 * (x + 1))"),
+		("const f = (a) => { return a + 1; }; log(f(x));", "log(x + 1)"),
+		("const f = (a) => a * 2; log(f(load()));", "log(load() * 2)"),
+		("const f = (a) => a * a; log(f(x));", "log(x * x)"),
+		("const f = (a) => ({ a }); log(f(x));", "({ a: x })"),
+		("log(((a) => a + 1)(2));", "log(2 + 1)"),
+		("const f = (a) => a(1); log(f((b) => b + 1));", "log(1 + 1)"),
+		(
+			"const f = (a: number): number => a + 1; log(f(x));",
+			"log((x satisfies number) + 1 satisfies number)",
+		),
+	] {
+		let Result = Eliminate(Code);
+
+		assert!(Result.contains(Expected), "{} => {}", Code, Result);
+	}
+
+	for (Code, Kept) in [
+		("const f = (a, b) => b + a; log(f(one(), two()));", "((a, b) => b + a)(one(), two())"),
+		("const f = (a) => 1; log(f(load()));", "((a) => 1)(load())"),
+		("const f = (a) => a * a; log(f(x + 1));", "((a) => a * a)(x + 1)"),
+		("const f = (a) => () => a; log(f(x));", "((a) => () => a)(x)"),
+		("const f = (a) => (g(), a); log(f(load()));", "((a) => (g(), a))(load())"),
+		("const f = (a) => c && a; log(f(load()));", "((a) => c && a)(load())"),
+		("const f = function (a) { return a; }; log(f(x));", "(function (a) { return a; })(x)"),
+		("const f = (a) => 1; log(f(y)); let y = 2;", "((a) => 1)(y)"),
+		("const f = (a) => c && a; log(f(y));", "((a) => c && a)(y)"),
+		("const f = (a) => ({ a }); log(f(() => 1));", "((a) => ({ a }))(() => 1)"),
+		("const f = (a) => (b = a); log(f(function () {}));", "((a) => (b = a))(function () {})"),
+		("const f = (a) => a; log(f(class {}));", "((a) => a)(class {})"),
+	] {
+		let Result = Eliminate(Code);
+
+		assert!(Result.contains(Kept), "{} => {}", Code, Result);
+	}
+}
+
+#[test]
+fn RemoveUnusedDeclarations() {
+	let Remove = Option { Dead:Dead::Remove, ..Option::default() };
+
+	let Result = EliminateWith("const unused = 42;\nfunction neverCalled() {}\nlog(1);\n", &Remove);
+
+	assert_eq!(Result, "log(1);\n");
+
+	let Result = EliminateWith("const a = 1; const b = a; log(2);", &Remove);
+
+	assert!(!Result.contains("const"), "{}", Result);
+
+	// Other scripts can use the top-level declarations of a script as
+	// globals.
+	let Result =
+		EliminateAs(".ts", "const unused = 42;\nfunction f() { const inner = 1; }\nf();\n", &Remove);
+
+	assert_eq!(Result, "const unused = 42;\nfunction f() { }\nf();\n");
+
+	let Component = Option { Dialect:Dialect::Tsx, ..Remove };
+
+	assert!(
+		EliminateWith("function C() { return null; } render(<C />);", &Component).contains("function C()")
+	);
+
+	for (Code, Kept) in [
+		("export const unused = 42;", "export const unused = 42"),
+		("const unused = load();", "const unused = load()"),
+		("let unused = 1; unused = 2;", "let unused = 1"),
+		("const unused = 1; type T = typeof unused;", "const unused = 1"),
+		("function f() { var unused = 1; eval(''); } f();", "var unused = 1"),
+		("const a = 1, unused = load(); log(a);", "unused = load()"),
+	] {
+		let Result = EliminateWith(Code, &Remove);
+
+		assert!(Result.contains(Kept), "{} => {}", Code, Result);
+	}
+
+	assert!(Eliminate("const unused = 42;").contains("const unused = 42"));
+
+	let Expression = Option { Dead:Dead::Expression, ..Option::default() };
+
+	for (Code, Expected) in [
+		("const unused = load();", "load();"),
+		("const unused = { a: load() };", "({ a: load() });"),
+		("function f() { let unused = load(); } f();", "function f() { load(); } f();"),
+	] {
+		assert_eq!(EliminateWith(Code, &Expression), Expected, "{}", Code);
+	}
+
+	let File = Builder::new().suffix(".mts").tempfile().expect("Cannot tempfile.");
+
+	fs::write(File.path(), "const unused = 42;\nfunction neverCalled() {}\nlog(1);\n")
+		.expect("Cannot write.");
+
+	let (_, Diagnostic) =
+		ProcessFileRecursive(File.path(), &Remove).expect("Cannot ProcessFileRecursive.");
+
+	assert_eq!(
+		Diagnostic.Removed,
+		vec![
+			Removed { Name:"unused".to_string(), Line:1 },
+			Removed { Name:"neverCalled".to_string(), Line:2 },
+		]
+	);
+}
+
+#[test]
+fn EliminateDeadStores() {
+	let Remove = Option { Store:Store::Remove, ..Option::default() };
+
+	assert_eq!(
+		EliminateWith("function f() { let status = 'idle'; status = load(); return 0; }", &Remove),
+		"function f() { let status; load(); return 0; }"
+	);
+
+	let Result = EliminateWith("function f() { let a = 1; log(a); a = 2; }", &Remove);
+
+	assert!(Result.contains("log(1)") && !Result.contains("a = 2"), "{}", Result);
+
+	for (Code, Kept) in [
+		("function f() { let a = 1; a = 2; log(a); }", "a = 2"),
+		("function f() { let a = 0; while (c()) { log(a); a = 1; } }", "a = 1"),
+		("function f() { let a = 0; const g = () => a; a = 1; return g; }", "a = 1"),
+		("function f() { let a = 0; a = 1; eval('a'); }", "a = 1"),
+		("function f() { const a = 0; a = 1; }", "a = 1"),
+		("export let a = 0; a = 1;", "a = 1"),
+		("function f(o) { let s = 1; const { a = s } = (s = 2, o); return a; }", "s = 2"),
+		("function f(l) { let s = 1; const [a = s] = (s = 2, l); return a; }", "s = 2"),
+		("function f(l) { let s = 1, a; [a = s] = (s = 2, l); return a; }", "s = 2"),
+		("function f(o) { let s = 1, a; ({ a = s } = (s = 2, o)); return a; }", "s = 2"),
+	] {
+		let Result = EliminateWith(Code, &Remove);
+
+		assert!(Result.contains(Kept), "{} => {}", Code, Result);
+	}
+
+	let Code = "function f() {\n\tlet status = 'idle';\n\tstatus = load();\n\treturn 0;\n}\nf();\n";
+
+	let File = Builder::new().suffix(".ts").tempfile().expect("Cannot tempfile.");
+
+	fs::write(File.path(), Code).expect("Cannot write.");
+
+	let (Result, Diagnostic) =
+		ProcessFileRecursive(File.path(), &Option { Store:Store::Report, ..Option::default() })
+			.expect("Cannot ProcessFileRecursive.");
+
+	assert_eq!(Result, Code);
+
+	assert_eq!(
+		Diagnostic.Store,
+		vec![
+			Stored { Name:"status".to_string(), Line:2 },
+			Stored { Name:"status".to_string(), Line:3 },
+		]
+	);
+}
+
+#[test]
+fn CountComponentsAsReads() {
+	let Run = |Code:&str| EliminateWith(Code, &Option { Dialect:Dialect::Tsx, ..Option::default() });
+
+	let Code = "const C = load(); f(C); const x = <C />;";
+
+	assert_eq!(Run(Code), Code);
+
+	assert_eq!(
+		Run("const C = load(); const el = <C><C.Item /></C>; render(el);"),
+		"const C = load(); render(<C><C.Item /></C>);"
+	);
+
+	assert_eq!(Run("const div = 1; render(<div />); f(div);"), "render(<div />); f(1);");
+}
+
+#[test]
+fn StopAtPassLimit() {
+	let File = Builder::new().suffix(".mts").tempfile().expect("Cannot tempfile.");
+
+	fs::write(File.path(), "const a = 1; const b = a; log(b);").expect("Cannot write.");
+
+	let (_, Diagnostic) = ProcessFileRecursive(File.path(), &Option::default())
+		.expect("Cannot ProcessFileRecursive.");
+
+	assert_eq!(Diagnostic.Convergence, Convergence::Converged);
+
+	assert_eq!(Diagnostic.to_string(), "Converged after 1 pass");
+
+	let (_, Diagnostic) = ProcessFileRecursive(File.path(), &Option { Pass:1, ..Option::default() })
+		.expect("Cannot ProcessFileRecursive.");
+
+	assert_eq!(Diagnostic.Convergence, Convergence::Converged);
+
+	let (Result, Diagnostic) =
+		ProcessFileRecursive(File.path(), &Option { Pass:0, ..Option::default() })
+			.expect("Cannot ProcessFileRecursive.");
+
+	assert_eq!(
+		Diagnostic,
+		Diagnostic::Struct {
+			Pass:0,
+			Convergence:Convergence::Limit,
+			Bailout:Vec::new(),
+			Removed:Vec::new(),
+			Store:Vec::new(),
+		}
+	);
+
+	assert!(Result.contains("const a = 1"));
+
+	fs::write(File.path(), "log(1);").expect("Cannot write.");
+
+	let (_, Diagnostic) = ProcessFileRecursive(File.path(), &Option { Pass:0, ..Option::default() })
+		.expect("Cannot ProcessFileRecursive.");
+
+	assert_eq!(Diagnostic.Convergence, Convergence::Converged);
+}
+
+use std::fs;
+
+use tempfile::Builder;
+
+use super::ProcessFileRecursive;
+use crate::Struct::Eliminate::{
+	Diagnostic::{self, Bailout, Convergence, Reason, Removed, Store as Stored},
+	Option::{Annotation, Comment as Placement, Dead, Dialect, Store, Struct as Option},
+};
@@ -0,0 +1,365 @@
+/// A change to the original source text, recorded against the spans of the
+/// parsed module.
+#[derive(Clone, Debug)]
+pub enum Edit {
+	/// Delete the text of a removed declaration, declarator or moved comment.
+	Remove(Span),
+	/// Replace the single use of a removed declaration with the text of its
+	/// initializer, as `key: init` for shorthand properties.
+	Replace {
+		/// Span of the identifier being replaced.
+		Span:Span,
+		/// Span of the initializer, whose own edits apply as well.
+		Init:Span,
+		/// Key to keep when the use is a shorthand property.
+		Key:Option<String>,
+		/// Member read on the initializer, as `.a` when the use is a
+		/// destructured property read as `props.a`.
+		Member:Option<String>,
+		/// Keyword and span of the type annotation the initializer keeps,
+		/// as in `init satisfies T`.
+		Type:Option<(&'static str, Span)>,
+	},
+	/// Parenthesize the expression spanning the text.
+	Paren(Span),
+	/// Put `;` in front of the statement spanning the text when its edited
+	/// text starts with `(`, `[`, `` ` ``, `+`, `-` or `/` and would otherwise
+	/// continue the previous statement, which ends at `Previous` without one.
+	Guard {
+		/// Span of the statement.
+		Span:Span,
+		/// End of the previous statement.
+		Previous:BytePos,
+	},
+}
+
+impl Edit {
+	/// The text the edit applies to.
+	pub fn Span(&self) -> Span {
+		match self {
+			Edit::Remove(Span)
+			| Edit::Paren(Span)
+			| Edit::Replace { Span, .. }
+			| Edit::Guard { Span, .. } => *Span,
+		}
+	}
+}
+
+/// Applies edits to the original source text, leaving every other byte of it
+/// untouched.
+pub struct Editor<'a> {
+	/// The original source text.
+	Source:&'a str,
+	/// Position of the first byte of `Source`.
+	Start:BytePos,
+	/// Comments of the file, for those moved in front of an initializer.
+	Comments:Option<&'a dyn Comments>,
+	/// Edits ordered by start, enclosing edits first.
+	Edits:Vec<Edit>,
+}
+
+impl<'a> Editor<'a> {
+	/// Applies the edits to the source text starting at `Start`.
+	pub fn Apply(
+		Source:&'a str,
+		Start:BytePos,
+		Comments:Option<&'a dyn Comments>,
+		mut Edits:Vec<Edit>,
+	) -> String {
+		// A removal of a span goes first, so it takes the parenthesis of an
+		// initializer that is a whole statement, as a moved function
+		// declaration is, and a parenthesis goes around a replacement of the
+		// same span. A guard goes before the edits of its statement.
+		Edits.sort_by_key(|Edit| {
+			let Rank = match Edit {
+				Edit::Remove(_) | Edit::Guard { .. } => 0,
+				Edit::Paren(_) => 1,
+				_ => 2,
+			};
+
+			(Edit.Span().lo, Reverse(Edit.Span().hi), Rank)
+		});
+
+		let Editor = Self { Source, Start, Comments, Edits };
+
+		let mut Output = String::with_capacity(Source.len());
+
+		Editor.Render(Start, Start + BytePos(Source.len() as u32), 0, &mut Output);
+
+		Output
+	}
+
+	/// Byte offset of `Position` in the source text.
+	fn Offset(&self, Position:BytePos) -> usize { (Position - self.Start).0 as usize }
+
+	/// The original text between two positions.
+	fn Text(&self, Lo:BytePos, Hi:BytePos) -> &'a str { &self.Source[self.Offset(Lo)..self.Offset(Hi)] }
+
+	/// Whether the text between two positions is only whitespace.
+	fn Blank(&self, Lo:BytePos, Hi:BytePos) -> bool { self.Text(Lo, Hi).trim().is_empty() }
+
+	/// Widens a removal so it leaves no stray whitespace: a removal that
+	/// fills its lines takes the whole lines, one that starts a line or
+	/// follows whitespace takes the spaces after it. The result never leaves
+	/// `Lo..Hi`.
+	fn Extend(&self, Start:BytePos, End:BytePos, Lo:BytePos, Hi:BytePos) -> (BytePos, BytePos) {
+		let Bytes = self.Source.as_bytes();
+
+		let Horizontal = |Byte:u8| Byte == b' ' || Byte == b'\t';
+
+		let mut Before = self.Offset(Start);
+
+		while Before > self.Offset(Lo) && Horizontal(Bytes[Before - 1]) {
+			Before -= 1;
+		}
+
+		let mut After = self.Offset(End);
+
+		while After < self.Offset(Hi) && Horizontal(Bytes[After]) {
+			After += 1;
+		}
+
+		let LineStart = Before == 0 || Bytes[Before - 1] == b'\n';
+
+		let Newline = if Bytes[After..self.Offset(Hi)].starts_with(b"\r\n") {
+			Some(2)
+		} else if Bytes[After..self.Offset(Hi)].starts_with(b"\n") {
+			Some(1)
+		} else if After == Bytes.len() {
+			Some(0)
+		} else {
+			None
+		};
+
+		let Position = |Offset:usize| self.Start + BytePos(Offset as u32);
+
+		match Newline {
+			Some(Length) if LineStart => {
+				(Position(Before), Position(After + Length))
+			},
+			_ if LineStart || Before < self.Offset(Start) => (Start, Position(After)),
+			_ => (Start, End),
+		}
+	}
+
+	/// Writes the comments attached in front of `Position` with their own
+	/// text and kind: a line comment ends its line, a block comment is
+	/// followed by a space.
+	fn Lead(&self, Position:BytePos, Output:&mut String) {
+		let Some(Comments) = self.Comments else {
+			return;
+		};
+
+		for Comment in Comments.get_leading(Position).unwrap_or_default() {
+			match Comment.kind {
+				CommentKind::Line => {
+					Output.push_str("//");
+
+					Output.push_str(&Comment.text);
+
+					Output.push('\n');
+				},
+				CommentKind::Block => {
+					Output.push_str("/*");
+
+					Output.push_str(&Comment.text);
+
+					Output.push_str("*/ ");
+				},
+			}
+		}
+	}
+
+	/// Writes the text between `Lo` and `Hi` with the edits inside it
+	/// applied, considering edits from index `From` on.
+	fn Render(&self, Lo:BytePos, Hi:BytePos, From:usize, Output:&mut String) {
+		let mut Cursor = Lo;
+
+		let mut Index = From;
+
+		while Index < self.Edits.len() {
+			let Span = self.Edits[Index].Span();
+
+			if Span.lo >= Hi {
+				break;
+			}
+
+			if Span.lo < Cursor || Span.hi > Hi {
+				Index += 1;
+
+				continue;
+			}
+
+			match &self.Edits[Index] {
+				Edit::Remove(_) => {
+					// Removals separated only by whitespace go as one.
+					let mut End = Span.hi;
+
+					let mut Next = Index + 1;
+
+					while let Some(Following) = self.Edits.get(Next) {
+						let Following = match Following {
+							_ if Following.Span().lo < End => {
+								Next += 1;
+
+								continue;
+							},
+							Edit::Remove(Following) => *Following,
+							_ => break,
+						};
+
+						if Following.hi > Hi || !self.Blank(End, Following.lo) {
+							break;
+						}
+
+						End = Following.hi;
+
+						Next += 1;
+					}
+
+					let (Start, End) = self.Extend(Span.lo, End, Cursor, Hi);
+
+					Output.push_str(self.Text(Cursor, Start));
+
+					Cursor = End;
+
+					Index = Next;
+
+					continue;
+				},
+				Edit::Replace { Init, Key, Member, Type, .. } => {
+					Output.push_str(self.Text(Cursor, Span.lo));
+
+					if let Some(Key) = Key {
+						Output.push_str(Key);
+
+						Output.push_str(": ");
+					}
+
+					self.Lead(Init.lo, Output);
+
+					let mut From = self.Edits.partition_point(|Edit| Edit.Span().lo < Init.lo);
+
+					// The removal of the statement that was the initializer.
+					if matches!(self.Edits.get(From), Some(Edit::Remove(Removed)) if Removed == Init) {
+						From += 1;
+					}
+
+					self.Render(Init.lo, Init.hi, From, Output);
+
+					if let Some(Member) = Member {
+						Output.push_str(Member);
+					}
+
+					if let Some((Keyword, Type)) = Type {
+						Output.push(' ');
+
+						Output.push_str(Keyword);
+
+						Output.push(' ');
+
+						Output.push_str(self.Text(Type.lo, Type.hi));
+					}
+				},
+				Edit::Guard { Previous, .. } => {
+					Output.push_str(self.Text(Cursor, Span.lo));
+
+					let mut Statement = String::new();
+
+					self.Render(Span.lo, Span.hi, Index + 1, &mut Statement);
+
+					let Continues = |Text:&str| Text.starts_with(['(', '[', '`', '+', '-', '/']);
+
+					if Continues(&Statement)
+						&& !Continues(self.Text(Span.lo, Span.hi))
+						&& !self.Text(*Previous - BytePos(1), *Previous).ends_with(';')
+					{
+						Output.push(';');
+					}
+
+					Output.push_str(&Statement);
+				},
+				Edit::Paren(_) => {
+					Output.push_str(self.Text(Cursor, Span.lo));
+
+					Output.push('(');
+
+					self.Render(Span.lo, Span.hi, Index + 1, Output);
+
+					Output.push(')');
+				},
+			}
+
+			Cursor = Span.hi;
+
+			Index += 1;
+		}
+
+		Output.push_str(self.Text(Cursor, Hi));
+	}
+}
+
+/// Records a [`Edit::Paren`] for every parenthesis the Inliner added and
+/// [`Parenthesizer`](super::Paren::Parenthesizer) kept, and a [`Edit::Guard`]
+/// for every expression statement that follows one which may end without a
+/// semicolon.
+#[derive(Default)]
+pub struct Marker {
+	/// The recorded edits.
+	pub Edits:Vec<Edit>,
+}
+
+impl Visit for Marker {
+	fn visit_paren_expr(&mut self, Paren:&ParenExpr) {
+		if Paren.span.is_dummy() && !Paren.expr.span().is_dummy() {
+			self.Edits.push(Edit::Paren(Paren.expr.span()));
+		}
+
+		Paren.visit_children_with(self);
+	}
+
+	fn visit_module_items(&mut self, Items:&[ModuleItem]) {
+		self.Guard(Items.iter().map(ModuleItem::as_stmt));
+
+		Items.visit_children_with(self);
+	}
+
+	fn visit_stmts(&mut self, Stmts:&[Stmt]) {
+		self.Guard(Stmts.iter().map(Some));
+
+		Stmts.visit_children_with(self);
+	}
+}
+
+impl Marker {
+	/// Records a [`Edit::Guard`] for every expression statement of a list that
+	/// follows an expression statement, a variable declaration, a `return` or
+	/// a `throw`, the statements that may end without a semicolon.
+	fn Guard<'a>(&mut self, Stmts:impl Iterator<Item = Option<&'a Stmt>>) {
+		let Stmts = Stmts.collect::<Vec<_>>();
+
+		for Pair in Stmts.windows(2) {
+			if let [Some(Previous), Some(Stmt::Expr(Next))] = Pair
+				&& matches!(
+					Previous,
+					Stmt::Expr(_)
+						| Stmt::Return(_)
+						| Stmt::Throw(_)
+						| Stmt::Decl(Decl::Var(_) | Decl::Using(_))
+				) {
+				self.Edits.push(Edit::Guard { Span:Next.span, Previous:Previous.span().hi });
+			}
+		}
+	}
+}
+
+use std::cmp::Reverse;
+
+use swc_common::{
+	BytePos,
+	Span,
+	Spanned,
+	comments::{CommentKind, Comments},
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
@@ -1,4 +1,5 @@
 #![allow(non_snake_case)]
+#![allow(clippy::tabs_in_doc_comments)]
 
 #[allow(dead_code)]
 #[tokio::main]
@@ -15,7 +16,7 @@
 ///
 /// # Example
 ///
-/// ```rust
+/// ```ignore
 /// #[tokio::main]
 /// async fn main() { (Struct::Binary::Command::Struct::Fn().Fn)().await }
 /// ```
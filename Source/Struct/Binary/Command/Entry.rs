@@ -19,6 +19,9 @@ pub struct Struct {
 
 	/// A vector of strings representing patterns to omit.
 	pub Omit:Omit,
+
+	/// The options controlling how variables are eliminated.
+	pub Eliminate:Eliminate,
 }
 
 impl Struct {
@@ -26,7 +29,7 @@ impl Struct {
 	///
 	/// This function initializes the Struct with the provided options,
 	/// generating the entry paths and cloning the omit patterns, parallel
-	/// flag, pattern, and separator from the options.
+	/// flag, pattern, separator and elimination options from the options.
 	///
 	/// # Arguments
 	///
@@ -43,16 +46,14 @@ impl Struct {
 			Parallel:Option.Parallel,
 			Pattern:Option.Pattern.clone(),
 			Separator:Option.Separator,
+			Eliminate:Eliminate::Fn(Option),
 		}
 	}
 }
 
-use crate::Struct::Binary::Command::Option::{
-	Omit,
-	Parallel,
-	Pattern,
-	Separator,
-	Struct as Option,
+use crate::Struct::{
+	Binary::Command::Option::{Omit, Parallel, Pattern, Separator, Struct as Option},
+	Eliminate::Option::Struct as Eliminate,
 };
 
 /// Type alias for a vector of vectors, where each inner vector contains the
@@ -4,23 +4,58 @@
 /// exclude patterns, omit patterns, parallel execution flag, pattern to match,
 /// root directory, and separator for file paths.
 pub struct Struct {
+	/// How the type annotation of a removed declaration is kept, either
+	/// `Satisfies`, `As` or `Refuse`.
+	pub Annotation:Annotation,
+
+	/// What happens to the comments of a removed declaration, either `Move`
+	/// or `Keep`.
+	pub Comment:Comment,
+
+	/// What happens to local declarations that are never used, either
+	/// `Keep`, `Remove` or `Expression`.
+	pub Dead:Dead,
+
+	/// The syntax files are parsed with, or `Auto` to pick it from the
+	/// extension.
+	pub Dialect:Dialect,
+
 	/// A vector of strings representing patterns to exclude.
 	pub Exclude:Vec<String>,
 
+	/// A flag allowing function and class declarations used once to be
+	/// inlined as expressions.
+	pub Function:Function,
+
+	/// A flag allowing trivially pure literals to be inlined across function,
+	/// loop and deferred-evaluation boundaries.
+	pub Literal:Literal,
+
 	/// A vector of strings representing patterns to omit.
 	pub Omit:Vec<String>,
 
 	/// A flag indicating whether to execute commands in parallel.
 	pub Parallel:Parallel,
 
+	/// The maximum number of inlining passes over a single file.
+	pub Pass:Pass,
+
 	/// A string pattern to match against the last element of each entry.
 	pub Pattern:Pattern,
 
+	/// A flag printing whole modules again instead of editing only the
+	/// changed spans.
+	pub Reprint:Reprint,
+
 	/// The root directory to start the walk from.
 	pub Root:String,
 
 	/// The separator used for file paths.
 	pub Separator:Separator,
+
+	/// What happens to assignments whose value is never read, either
+	/// `Keep`, `Report` or `Remove`.
+	pub Store:Store,
 }
 
 impl Struct {
@@ -40,16 +75,28 @@ impl Struct {
 	/// Returns a new instance of Struct.
 	pub fn Fn(Option { Separator, .. }:Option) -> Self {
 		Self {
+			Annotation:Command()
+				.get_one::<String>("Annotation")
+				.expect("Cannot Annotation.")
+				.to_owned(),
+			Comment:Command().get_one::<String>("Comment").expect("Cannot Comment.").to_owned(),
+			Dead:Command().get_one::<String>("Dead").expect("Cannot Dead.").to_owned(),
+			Dialect:Command().get_one::<String>("Dialect").expect("Cannot Dialect.").to_owned(),
 			Exclude:Command()
 				.get_one::<String>("Exclude")
 				.expect("Cannot Exclude.")
 				.split(" ")
 				.map(|Exclude| Exclude.to_string())
 				.collect::<Vec<_>>(),
+			Function:Command().get_flag("Function"),
+			Literal:Command().get_flag("Literal"),
 			Parallel:Command().get_flag("Parallel"),
+			Pass:Command().get_one::<usize>("Pass").expect("Cannot Pass.").to_owned(),
 			Pattern:Command().get_one::<String>("Pattern").expect("Cannot Pattern.").to_owned(),
+			Reprint:Command().get_flag("Reprint"),
 			Root:Command().get_one::<String>("Root").expect("Cannot Root.").to_owned(),
 			Separator,
+			Store:Command().get_one::<String>("Store").expect("Cannot Store.").to_owned(),
 			Omit:Command()
 				.get_many::<String>("Omit")
 				.expect("Cannot Omit.")
@@ -64,14 +111,47 @@ use crate::{Fn::Binary::Command::Fn as Command, Struct::Binary::Command::Struct
 /// Type alias for a vector of strings representing command options.
 pub type Command = Vec<String>;
 
+/// Type alias for the name of the policy for type annotations of removed
+/// declarations.
+pub type Annotation = String;
+
+/// Type alias for the name of the policy for comments of removed
+/// declarations.
+pub type Comment = String;
+
+/// Type alias for the name of the policy for declarations that are never
+/// used.
+pub type Dead = String;
+
+/// Type alias for the name of the syntax files are parsed with.
+pub type Dialect = String;
+
+/// Type alias for a boolean flag allowing function and class declarations to
+/// be inlined.
+pub type Function = bool;
+
+/// Type alias for a boolean flag allowing literals across evaluation
+/// boundaries.
+pub type Literal = bool;
+
 /// Type alias for a boolean flag indicating parallel execution.
 pub type Parallel = bool;
 
+/// Type alias for the maximum number of inlining passes over a file.
+pub type Pass = usize;
+
 /// Type alias for a string pattern to match.
 pub type Pattern = String;
 
+/// Type alias for a boolean flag printing whole modules again.
+pub type Reprint = bool;
+
 /// Type alias for a character used as a separator for file paths.
 pub type Separator = char;
 
+/// Type alias for the name of the policy for assignments whose value is
+/// never read.
+pub type Store = String;
+
 /// Type alias for a vector of strings representing patterns to omit.
 pub type Omit = Vec<String>;
@@ -0,0 +1,150 @@
+/// Represents the outcome of eliminating variables from a single file.
+///
+/// This struct holds how many inlining passes ran, whether the file reached a
+/// fixpoint, which scopes were left alone, which unused declarations were
+/// removed and which stores are never read, so callers can report files that
+/// did not converge or were only partly eliminated.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Struct {
+	/// The number of inlining passes that changed the file.
+	pub Pass:usize,
+
+	/// Whether and why the passes stopped.
+	pub Convergence:Convergence,
+
+	/// Constructs that made their scopes unsafe to eliminate in.
+	pub Bailout:Vec<Bailout>,
+
+	/// Declarations removed because they were never used.
+	pub Removed:Vec<Removed>,
+
+	/// Assignments whose value is never read, removed or only reported as
+	/// the `Store` option asks.
+	pub Store:Vec<Store>,
+}
+
+/// Represents an assignment to a local binding that no later read observes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Store {
+	/// The name of the assigned binding.
+	pub Name:String,
+
+	/// The line the assignment starts on.
+	pub Line:usize,
+}
+
+/// Represents a declaration removed because nothing used it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Removed {
+	/// The name the declaration bound.
+	pub Name:String,
+
+	/// The line the declaration started on.
+	pub Line:usize,
+}
+
+/// Represents a construct that reaches bindings by name at runtime, so the
+/// bindings of the scopes around it were left as they are.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bailout {
+	/// What the construct is.
+	pub Reason:Reason,
+
+	/// The line the construct starts on.
+	pub Line:usize,
+}
+
+/// Represents the kinds of construct that reach bindings by name at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reason {
+	/// A direct `eval(...)`, which can read and write any binding in scope.
+	Eval,
+
+	/// A `with` statement, whose body can resolve any name to a property.
+	With,
+
+	/// `arguments` in a sloppy-mode function, which aliases its parameters.
+	Arguments,
+}
+
+/// Represents why the inlining passes over a file stopped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Convergence {
+	/// The last pass inlined nothing, so the file reached its fixpoint.
+	#[default]
+	Converged,
+
+	/// The maximum number of passes ran and another one would still inline.
+	Limit,
+
+	/// The last pass produced a module seen before, this many passes earlier,
+	/// so further passes would only cycle.
+	Oscillation(usize),
+}
+
+impl fmt::Display for Struct {
+	fn fmt(&self, Formatter:&mut fmt::Formatter) -> fmt::Result {
+		match self.Convergence {
+			Convergence::Converged => write!(Formatter, "Converged after {}", Passes(self.Pass)),
+			Convergence::Limit => {
+				write!(Formatter, "Cannot converge: still inlining after the limit of {}", Passes(self.Pass))
+			},
+			Convergence::Oscillation(Period) => {
+				write!(
+					Formatter,
+					"Cannot converge: pass {} repeats the module of pass {}",
+					self.Pass,
+					self.Pass - Period
+				)
+			},
+		}?;
+
+		for Bailout in &self.Bailout {
+			write!(Formatter, "\n{}", Bailout)?;
+		}
+
+		for Removed in &self.Removed {
+			write!(Formatter, "\n{}", Removed)?;
+		}
+
+		for Store in &self.Store {
+			write!(Formatter, "\n{}", Store)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl fmt::Display for Bailout {
+	fn fmt(&self, Formatter:&mut fmt::Formatter) -> fmt::Result {
+		let Why = match self.Reason {
+			Reason::Eval => "direct `eval` can reach every binding in scope by name",
+			Reason::With => "`with` can resolve every name in its body to a property",
+			Reason::Arguments => "`arguments` aliases the parameters of this sloppy-mode function",
+		};
+
+		write!(Formatter, "Cannot eliminate around line {}: {}", self.Line, Why)
+	}
+}
+
+impl fmt::Display for Removed {
+	fn fmt(&self, Formatter:&mut fmt::Formatter) -> fmt::Result {
+		write!(Formatter, "Removed unused `{}` on line {}", self.Name, self.Line)
+	}
+}
+
+impl fmt::Display for Store {
+	fn fmt(&self, Formatter:&mut fmt::Formatter) -> fmt::Result {
+		write!(Formatter, "Store to `{}` on line {} is never read", self.Name, self.Line)
+	}
+}
+
+/// The number of passes, in words.
+fn Passes(Count:usize) -> String {
+	match Count {
+		1 => "1 pass".to_string(),
+		Count => format!("{} passes", Count),
+	}
+}
+
+use std::fmt;
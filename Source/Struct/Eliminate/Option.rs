@@ -0,0 +1,183 @@
+/// Represents the options for eliminating variables from a file.
+///
+/// This struct holds the settings that decide how far the Inliner may go when
+/// rewriting code.
+#[derive(Clone, Debug)]
+pub struct Struct {
+	/// A flag allowing trivially pure literals to move across function, loop
+	/// and deferred-evaluation boundaries.
+	pub Literal:Literal,
+
+	/// The maximum number of inlining passes over a single file.
+	pub Pass:Pass,
+
+	/// What happens to the comments of a removed declaration.
+	pub Comment:Comment,
+
+	/// A flag printing the whole module again instead of editing only the
+	/// changed spans of the original text.
+	pub Reprint:Reprint,
+
+	/// The syntax files are parsed with.
+	pub Dialect:Dialect,
+
+	/// How the type annotation of a removed declaration is kept at the use
+	/// site.
+	pub Annotation:Annotation,
+
+	/// A flag inlining function and class declarations used once as
+	/// function and class expressions.
+	pub Function:Function,
+
+	/// What happens to local declarations that are never used.
+	pub Dead:Dead,
+
+	/// What happens to assignments whose value is never read.
+	pub Store:Store,
+}
+
+/// Represents what happens to the comments attached to a removed declaration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Comment {
+	/// Move them to the use site, in front of the inlined initializer.
+	#[default]
+	Move,
+
+	/// Keep them in place, in front of the statement that follows.
+	Keep,
+}
+
+/// Represents the syntax files are parsed with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dialect {
+	/// Pick the syntax from the file extension.
+	#[default]
+	Auto,
+
+	/// TypeScript.
+	Typescript,
+
+	/// TypeScript with JSX.
+	Tsx,
+
+	/// JavaScript.
+	Javascript,
+
+	/// JavaScript with JSX.
+	Jsx,
+}
+
+/// Represents how the type annotation of a removed declaration, as in
+/// `const port: number = ...`, is kept at the use site.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Annotation {
+	/// Inline `init satisfies T`, which keeps the check against `T`.
+	#[default]
+	Satisfies,
+
+	/// Inline `init as T`, which keeps the type `T`. The `<T>init` form is
+	/// never used, since it reads as JSX in `.tsx` files.
+	As,
+
+	/// Never inline an annotated declaration.
+	Refuse,
+}
+
+/// Represents what happens to a local declaration that is never read, written
+/// or referred to from a type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dead {
+	/// Keep it.
+	#[default]
+	Keep,
+
+	/// Remove it when its initializer is pure.
+	Remove,
+
+	/// Remove it, keeping an impure initializer as an expression statement
+	/// when the declaration has no other declarator.
+	Expression,
+}
+
+/// Represents what happens to a store to a local binding that no later read
+/// can observe.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Store {
+	/// Keep it.
+	#[default]
+	Keep,
+
+	/// Keep it and report it.
+	Report,
+
+	/// Remove it and report it, keeping an impure right-hand side as an
+	/// expression of its own.
+	Remove,
+}
+
+impl Default for Struct {
+	fn default() -> Self {
+		Self {
+			Literal:false,
+			Pass:16,
+			Comment:Comment::default(),
+			Reprint:false,
+			Dialect:Dialect::default(),
+			Annotation:Annotation::default(),
+			Function:false,
+			Dead:Dead::default(),
+			Store:Store::default(),
+		}
+	}
+}
+
+impl Struct {
+	/// Creates a new instance of the Struct.
+	///
+	/// This function initializes the Struct from the command-line options.
+	///
+	/// # Arguments
+	///
+	/// * `Option` - A reference to an Option struct containing initialization
+	///   parameters.
+	///
+	/// # Returns
+	///
+	/// Returns a new instance of Struct.
+	pub fn Fn(Option:&Option) -> Self {
+		Self {
+			Literal:Option.Literal,
+			Pass:Option.Pass,
+			Comment:match Option.Comment.as_str() {
+				"Keep" => Comment::Keep,
+				_ => Comment::Move,
+			},
+			Reprint:Option.Reprint,
+			Dialect:match Option.Dialect.as_str() {
+				"Typescript" => Dialect::Typescript,
+				"Tsx" => Dialect::Tsx,
+				"Javascript" => Dialect::Javascript,
+				"Jsx" => Dialect::Jsx,
+				_ => Dialect::Auto,
+			},
+			Annotation:match Option.Annotation.as_str() {
+				"As" => Annotation::As,
+				"Refuse" => Annotation::Refuse,
+				_ => Annotation::Satisfies,
+			},
+			Function:Option.Function,
+			Dead:match Option.Dead.as_str() {
+				"Remove" => Dead::Remove,
+				"Expression" => Dead::Expression,
+				_ => Dead::Keep,
+			},
+			Store:match Option.Store.as_str() {
+				"Report" => Store::Report,
+				"Remove" => Store::Remove,
+				_ => Store::Keep,
+			},
+		}
+	}
+}
+
+use crate::Struct::Binary::Command::Option::{Function, Literal, Pass, Reprint, Struct as Option};
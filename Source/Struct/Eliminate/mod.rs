@@ -0,0 +1,2 @@
+pub mod Diagnostic;
+pub mod Option;
@@ -1 +1,2 @@
 pub mod Binary;
+pub mod Eliminate;